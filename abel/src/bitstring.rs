@@ -3,12 +3,13 @@ use std::fmt;
 use std::any::Any;
 use std::ops::Deref;
 
+use crate::error::InternalError;
 use crate::object::Object;
 //use crate::typefy::IntoType;
 use crate::marker::ScalarValued;
 use crate::util;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Bitstring {
     internal: String,
 }
@@ -43,7 +44,19 @@ impl Object for Bitstring{
     }
 
     fn type_name(&self) -> &'static str {
-        "Binary"
+        "Bitstring"
+    }
+
+    fn clone_box(&self) -> Result<Box<dyn Object>, InternalError> {
+        Ok(Box::new(self.clone()))
+    }
+
+    fn fmt_display(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+
+    fn fmt_debug(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
     }
 }
 
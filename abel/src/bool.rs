@@ -1,5 +1,6 @@
 use std::fmt;
 use std::any::Any;
+use std::str::FromStr;
 use std::ops::Deref;
 
 use crate::error::{Error, InternalError, ErrorKind};
@@ -7,7 +8,7 @@ use crate::object::Object;
 use crate::typefy::IntoType;
 use crate::marker::ScalarValued;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Bool {
     internal: bool,
 }
@@ -24,8 +25,12 @@ impl Bool {
             internal
         }
     }
+}
+
+impl FromStr for Bool {
+    type Err = InternalError;
 
-    pub fn from_str(src_str: &str) -> Result<Self, InternalError> {
+    fn from_str(src_str: &str) -> Result<Self, Self::Err> {
         match src_str {
             "true" => Ok(Self::new(true)),
             "false" => Ok(Self::new(false)),
@@ -34,7 +39,7 @@ impl Bool {
                 ErrorKind::FailedToIdentify
             ))
         }
-    } 
+    }
 }
 
 impl Object for Bool {
@@ -49,6 +54,18 @@ impl Object for Bool {
     fn type_name(&self) -> &'static str {
         "Bool"
     }
+
+    fn clone_box(&self) -> Result<Box<dyn Object>, InternalError> {
+        Ok(Box::new(self.clone()))
+    }
+
+    fn fmt_display(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+
+    fn fmt_debug(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
 }
 
 impl Deref for Bool {
@@ -1,12 +1,13 @@
 use std::fmt;
 use std::any::Any;
 
+use crate::error::InternalError;
 use crate::object::Object;
 use crate::marker::ScalarValued;
 use crate::integer::Integer;
 use crate::double::Double;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Complex {
     real: f64,
     imag: f64,
@@ -15,14 +16,14 @@ pub struct Complex {
 impl Complex {
     pub fn new(real: f64, imag: f64) -> Self {
         Complex {
-            real: real,
-            imag: imag
+            real,
+            imag
         }
     }
 
     pub fn from_f64(real: f64) -> Self {
         Complex {
-            real: real,
+            real,
             imag: 0.0
         }
     }
@@ -57,6 +58,13 @@ impl Complex {
     pub fn is_zero(&self) -> bool {
         self.real == 0.0 && self.imag == 0.0
     }
+
+    /// Form in JSON+ text, e.g. `1.0+2.0j`, as accepted by
+    /// `converter::identify_complex`.
+    pub fn to_json_plus(&self) -> String {
+        let sign = if self.imag.is_sign_negative() { '-' } else { '+' };
+        format!("{:?}{}{:?}j", self.real, sign, self.imag.abs())
+    }
 }
 
 impl Object for Complex {
@@ -71,6 +79,18 @@ impl Object for Complex {
     fn type_name(&self) -> &'static str {
         "Complex"
     }
+
+    fn clone_box(&self) -> Result<Box<dyn Object>, InternalError> {
+        Ok(Box::new(self.clone()))
+    }
+
+    fn fmt_display(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_json_plus())
+    }
+
+    fn fmt_debug(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
 }
 
 impl fmt::Display for Complex {
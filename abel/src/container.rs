@@ -1,4 +1,4 @@
-use crate::error::InternalError;
+use crate::error::{Error, ErrorKind, InternalError};
 use crate::object::Object;
 use crate::typefy::IntoType;
 use crate::marker::ScalarLikePrimitive;
@@ -28,4 +28,11 @@ pub trait Container<KeyType> {
     -> Result<&mut Self, InternalError>
     where
         SourceType: 'static + IntoType + ScalarLikePrimitive;
+
+    /// Removes the value at `k` and returns it. Fails with `KeyNotFound`, or
+    /// `IndexOutOfRange` for a list, if there is no value at `k`. Containers
+    /// that don't support removal always fail with `KeyNotFound`.
+    fn remove(&mut self, _k: KeyType) -> Result<Box<dyn Object>, InternalError> {
+        Err(InternalError::new("Container does not support removal.", ErrorKind::KeyNotFound))
+    }
 }
\ No newline at end of file
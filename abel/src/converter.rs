@@ -40,10 +40,7 @@ pub fn as_bool(src_str: &str) -> Result<Bool, ParserError> {
 }
 
 pub fn is_integer(src_str: &str) -> bool {
-    match src_str.parse::<i32>() {
-        Ok(_) => true,
-        Err(_) => false,
-    }
+    src_str.parse::<i32>().is_ok()
 }
 
 pub fn as_integer(src_str: &str) -> Result<Integer, ParserError> {
@@ -63,10 +60,7 @@ pub fn as_integer(src_str: &str) -> Result<Integer, ParserError> {
 }
 
 pub fn is_double(src_str: &str) -> bool {
-    match src_str.parse::<f64>() {
-        Ok(_) => true,
-        Err(_) => false,
-    }
+    src_str.parse::<f64>().is_ok()
 }
 
 pub fn as_double(src_str: &str) -> Result<Double, ParserError> {
@@ -84,7 +78,7 @@ pub fn as_double(src_str: &str) -> Result<Double, ParserError> {
 }
 
 pub fn is_binary(src_str: &str) -> bool {
-    return util::is_valid_binary_string(src_str);
+    util::is_valid_binary_string(src_str)
 }
 
 pub fn as_binary(src_str: &str) -> Result<Binary, ParserError> {
@@ -98,7 +92,7 @@ pub fn as_binary(src_str: &str) -> Result<Binary, ParserError> {
 }
 
 pub fn is_bitstring(src_str: &str) -> bool {
-    return util::is_valid_bitstring(src_str);
+    util::is_valid_bitstring(src_str)
 }
 
 pub fn as_bitstring(src_str: &str) -> Result<Bitstring, ParserError> {
@@ -119,6 +113,12 @@ pub struct DataTypeIdentifier {
     imag_string: String
 }
 
+impl Default for DataTypeIdentifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl DataTypeIdentifier {
     pub fn new() -> Self {
         Self {
@@ -137,35 +137,28 @@ pub fn identify_complex(src_str: &str) -> DataTypeIdentifier {
     let mut dti = DataTypeIdentifier::new();
     dti.type_string = String::from("Complex");
 
-    let mut ret = true;
     let strlen = src_str.len();
     let mut position_pivot_sign: usize = 0;
     let mut has_pivot_sign: bool = false;
     let src_chars: Vec<char> = src_str.chars().collect();
 
     if src_str == "j" { // only 'j', okay
-        ret = true;
         dti.is_identified = true;
         dti.imag_string = String::from("1.0");
         dti.real_string = String::from("0.0");
     } else if src_str == "+j" {
-        ret = true;
         dti.is_identified = true;
         dti.imag_string = String::from("+1.0");
         dti.real_string = String::from("0.0");
     } else if src_str == "-j" {
-        ret = true;
         dti.is_identified = true;
         dti.imag_string = String::from("-1.0");
         dti.real_string = String::from("0.0");
-    } else if !(src_chars[strlen - 1] == 'j') {
+    } else if src_chars[strlen - 1] != 'j' {
         // if last symbol is not 'j', check if the whole string is a double
         if is_double(src_str) {
-            ret = true;
             dti.is_identified = true;
             dti.real_string = src_str.to_owned();
-        } else {
-            ret = false;
         }
     } else { // Symbol 'j' at the end and has at least 2 characters.
         // Search for the pivot sign from one letter before last `j`.
@@ -177,9 +170,9 @@ pub fn identify_complex(src_str: &str) -> DataTypeIdentifier {
                     // Must be (digit, 'j', or '.') on right side,
                     // (digit or '.') on left side
                     if (src_chars[index + 1] == 'j' || src_chars[index + 1] == '.'
-                            || src_chars[index + 1].is_digit(10))
+                            || src_chars[index + 1].is_ascii_digit())
                          && (src_chars[index - 1] == '.'
-                             || src_chars[index - 1].is_digit(10))
+                             || src_chars[index - 1].is_ascii_digit())
                     {
                         //print!("Found pivot sign in the middle, at {} \n", index);
                         has_pivot_sign = true;
@@ -193,7 +186,6 @@ pub fn identify_complex(src_str: &str) -> DataTypeIdentifier {
                     if strlen >= 2 && (src_chars[index + 1] == '-'
                                        || src_chars[index + 1] == '+') 
                     {
-                        ret = false;
                         break;
                     }
                     // if no pivot sign found, first one is.
@@ -215,7 +207,6 @@ pub fn identify_complex(src_str: &str) -> DataTypeIdentifier {
                 // Sign is the first symbol
                 if position_pivot_sign == 0 {
                     if src_chars[1] == 'j' { // Expressions '-j' or '+j' are okay
-                        ret = true;
                         dti.is_identified = true;
                         dti.real_string = String::from("0.0");
                         //dti.imag_string = src_str;
@@ -228,8 +219,7 @@ pub fn identify_complex(src_str: &str) -> DataTypeIdentifier {
                         // Test symbols from (include) the sign to the one
                         // before symbol 'j'.
                         let subliteral = &src_str[0..strlen-1];
-                        ret = is_double(subliteral);
-                        if ret {
+                        if is_double(subliteral) {
                             dti.is_identified = true;
                             dti.real_string = String::from("0.0");
                             dti.imag_string = String::from(subliteral);
@@ -243,12 +233,11 @@ pub fn identify_complex(src_str: &str) -> DataTypeIdentifier {
                     let leftsub = &src_str[0..position_pivot_sign];
                     let rightsub = &src_str[position_pivot_sign..(strlen-1)];
                     // Identify both sub-strings.
-                    ret = is_double(leftsub) && is_double(rightsub);
-                    if ret {
+                    if is_double(leftsub) && is_double(rightsub) {
                         dti.is_identified = true;
                         // If left sub in empty, pure imaginary, set real
                         // part to "0.0"
-                        if leftsub == "" {
+                        if leftsub.is_empty() {
                             dti.real_string = String::from("0.0");
                         } else {
                             dti.real_string = String::from(leftsub);    
@@ -260,15 +249,14 @@ pub fn identify_complex(src_str: &str) -> DataTypeIdentifier {
         } else { // no pivot sign
             // Note: case of only 'j' has been discussed at the beginning
             let sub = &src_str[0..strlen-1];
-            ret = is_double(sub);
-            if ret {
+            if is_double(sub) {
                 dti.is_identified = true;
                 dti.real_string = String::from("0.0");
                 dti.imag_string = String::from(sub);
             }
         }
     }
-    return dti;
+    dti
 }
 
 pub fn is_complex(src_str: &str) -> bool {
@@ -4,7 +4,6 @@ use crate::util::{is_opening_symbol, is_closing_symbol,
 pub struct DelimiterPair {
     opening_symbol: char,
     closing_symbol: char,
-    position_opening: Vec<i32>, //vec![i32; 2], // [line, column]
     position_closing: Vec<i32>, //vec![i32; 2],
     match_counter: i32,
 }
@@ -20,6 +19,12 @@ pub struct DelimiterMatch {
     total_matched_pairs: i32
 }
 
+impl Default for DelimiterMatch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl DelimiterMatch {
     pub fn new() -> Self {
         DelimiterMatch {
@@ -28,11 +33,10 @@ impl DelimiterMatch {
         }
     }
 
-    fn init_pair(&mut self, opening_sym: char, line: i32, column: i32) {
+    fn init_pair(&mut self, opening_sym: char) {
         let pair = DelimiterPair {
             opening_symbol: opening_sym,
             closing_symbol: get_closing_symbol_by_opening(opening_sym).unwrap(),
-            position_opening: vec![line, column],
             position_closing: vec![-999, -999],
             match_counter: 1
         };
@@ -45,17 +49,17 @@ impl DelimiterMatch {
             for pair in &mut self.pair_register {
                 // Pairs with the same opening symbol must be updated.
                 // Matched pair needs NO update.
-                if !is_matched(&pair) && pair.opening_symbol == curstr {
+                if !is_matched(pair) && pair.opening_symbol == curstr {
                     pair.match_counter += 1;
                 }
             }
             // Initialise a delimiter pair object by opening symbol and store
             // it in register.
-            self.init_pair(curstr, line, column);
+            self.init_pair(curstr);
         } else if is_closing_symbol(curstr) {
             for pair in &mut self.pair_register {
                 // Pairs with the same closing symbol must be updated.
-                if !is_matched(&pair) && pair.closing_symbol == curstr {
+                if !is_matched(pair) && pair.closing_symbol == curstr {
                     pair.match_counter -= 1;
                     // Match found. Store the position of closing symbol.
                     if pair.match_counter == 0 {
@@ -67,11 +71,10 @@ impl DelimiterMatch {
         }
     }
 
+    #[cfg(test)]
     fn scan(&mut self, target: &str) {
-        let mut column = 0;
-        for ch in target.chars() {
-            self.match_symbol(ch, 0, column);
-            column += 1;
+        for (column, ch) in target.chars().enumerate() {
+            self.match_symbol(ch, 0, column as i32);
         }
     }
 
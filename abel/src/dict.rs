@@ -1,4 +1,6 @@
+use std::fmt;
 use std::any::Any;
+use std::hash::{Hash, Hasher};
 use std::collections::HashMap;
 
 use crate::error::{Error, InternalError, ErrorKind};
@@ -6,46 +8,263 @@ use crate::object::Object;
 use crate::marker::ScalarLikePrimitive;
 use crate::typefy::IntoType;
 use crate::container::Container;
+use crate::equality;
+use crate::pointer;
+use crate::query::{self, Match};
+use crate::json_writer::escape_string;
 
+/// Dictionary that keeps its keys in insertion order. Replacing the value
+/// of an existing key keeps the key at its original position.
 pub struct Dict {
-    internal: HashMap<String, Box<dyn Object>>,
+    internal: Vec<(String, Box<dyn Object>)>,
+    key_index: HashMap<String, usize>,
+}
+
+impl Default for Dict {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Dict {
     pub fn new() -> Dict {
         Dict {
-            internal: HashMap::<String, Box<dyn Object>>::new()
+            internal: Vec::<(String, Box<dyn Object>)>::new(),
+            key_index: HashMap::<String, usize>::new()
         }
     }
 
     pub fn has_key(&self, key: &str) -> bool {
-        self.internal.contains_key(key)
+        self.key_index.contains_key(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.internal.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.internal.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.internal.clear();
+        self.key_index.clear();
+    }
+
+    /// Removes the most recently inserted key and returns it with its value.
+    pub fn pop(&mut self) -> Result<(String, Box<dyn Object>), InternalError> {
+        match self.internal.pop() {
+            Some((key, value)) => {
+                self.key_index.remove(&key);
+                Ok((key, value))
+            },
+            None => Err(InternalError::new(
+                "Cannot pop from an empty dictionary.",
+                ErrorKind::KeyNotFound
+            ))
+        }
     }
 
     pub fn insert<T>(&mut self, key: &str, obj: T)
     where
         T: 'static + Object
     {
-        self.internal.insert(key.to_owned(), Box::new(obj));
+        self.insert_box(key, Box::new(obj));
     }
 
     pub fn insert_box(&mut self, key: &str, obj_boxed: Box<dyn Object>) {
-        self.internal.insert(key.to_owned(), obj_boxed);
+        match self.key_index.get(key) {
+            Some(&position) => self.internal[position].1 = obj_boxed,
+            None => {
+                self.key_index.insert(key.to_owned(), self.internal.len());
+                self.internal.push((key.to_owned(), obj_boxed));
+            }
+        }
     }
 
     pub fn insert_from<T>(&mut self, key: &str, prim_type: T)
     where
         T: 'static + IntoType + ScalarLikePrimitive
     {
-        self.internal.insert(key.to_owned(), Box::new(prim_type.into_type()));
+        self.insert_box(key, Box::new(prim_type.into_type()));
     }
     
-    fn get(&self, k: &str) -> Option<&Box<dyn Object>> {
-        self.internal.get(k)
+    pub(crate) fn get(&self, k: &str) -> Option<&dyn Object> {
+        self.key_index.get(k).map(|&position| self.internal[position].1.as_ref())
+    }
+
+    pub(crate) fn get_mut(&mut self, k: &str) -> Option<&mut Box<dyn Object>> {
+        match self.key_index.get(k) {
+            Some(&position) => Some(&mut self.internal[position].1),
+            None => None,
+        }
+    }
+
+    /// Deep copy of the dictionary. Fails if any value, at any depth,
+    /// doesn't support cloning.
+    pub fn try_clone(&self) -> Result<Dict, InternalError> {
+        let mut internal = Vec::<(String, Box<dyn Object>)>::new();
+        for (key, value) in self.internal.iter() {
+            internal.push((key.clone(), value.clone_box()?));
+        }
+        Ok(Dict {
+            internal,
+            key_index: self.key_index.clone()
+        })
+    }
+
+    /// Key-value pairs in insertion order, used by writers.
+    pub(crate) fn entries(&self) -> Vec<(&String, &dyn Object)> {
+        self.iter().map(|(key, value)| (key, value.as_ref())).collect()
+    }
+
+    /// Key-value pairs sorted by key, used by writers.
+    pub(crate) fn sorted_entries(&self) -> Vec<(&String, &dyn Object)> {
+        let mut entries = self.entries();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        entries
+    }
+}
+
+/// Access by JSON Pointer, e.g. `get_path::<Double>("/servers/2/port")`
+impl Dict {
+    pub fn get_path<TargetType>(&self, path: &str) -> Result<&TargetType, InternalError>
+    where
+        TargetType: 'static + Object
+    {
+        pointer::get_path(self, path)
+    }
+
+    pub fn get_path_mut<TargetType>(&mut self, path: &str)
+    -> Result<&mut TargetType, InternalError>
+    where
+        TargetType: 'static + Object
+    {
+        pointer::get_path_mut(self, path)
+    }
+
+    /// Sets a value, creating missing containers on the way. Gaps in lists
+    /// are filled with nulls, at most `pointer::MAX_LIST_GAP` of them, and
+    /// `-` appends to a list.
+    pub fn set_path<SourceType>(&mut self, path: &str, obj: SourceType)
+    -> Result<&mut Self, InternalError>
+    where
+        SourceType: 'static + Object
+    {
+        self.set_path_box(path, Box::new(obj))
+    }
+
+    pub fn set_path_box(&mut self, path: &str, obj_boxed: Box<dyn Object>)
+    -> Result<&mut Self, InternalError>
+    {
+        pointer::set_path(self, path, obj_boxed)?;
+        Ok(self)
     }
+}
 
-    fn get_mut(&mut self, k: &str) -> Option<&mut Box<dyn Object>> {
-        self.internal.get_mut(k)
+/// JSONPath queries, e.g. `query("$.servers[?(@.enabled == true)].host")`
+impl Dict {
+    pub fn query(&self, expr: &str) -> Result<Vec<Match<'_>>, InternalError> {
+        query::select(self, expr)
+    }
+}
+
+/// Iteration in insertion order
+impl Dict {
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.internal.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &Box<dyn Object>> {
+        self.internal.iter().map(|(_, value)| value)
+    }
+
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            inner: self.internal.iter()
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_> {
+        IterMut {
+            inner: self.internal.iter_mut()
+        }
+    }
+}
+
+/// Iterator over key-value pairs of a `Dict`.
+pub struct Iter<'a> {
+    inner: std::slice::Iter<'a, (String, Box<dyn Object>)>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (&'a String, &'a Box<dyn Object>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, value)| (key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// Iterator over key-value pairs of a `Dict`, values are mutable.
+pub struct IterMut<'a> {
+    inner: std::slice::IterMut<'a, (String, Box<dyn Object>)>,
+}
+
+impl<'a> Iterator for IterMut<'a> {
+    type Item = (&'a String, &'a mut Box<dyn Object>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, value)| (&*key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> IntoIterator for &'a Dict {
+    type Item = (&'a String, &'a Box<dyn Object>);
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Dict {
+    type Item = (&'a String, &'a mut Box<dyn Object>);
+    type IntoIter = IterMut<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl IntoIterator for Dict {
+    type Item = (String, Box<dyn Object>);
+    type IntoIter = std::vec::IntoIter<(String, Box<dyn Object>)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.internal.into_iter()
+    }
+}
+
+/// Builds a dictionary from key-value pairs. A later pair replaces the value
+/// of an earlier pair with the same key.
+impl FromIterator<(String, Box<dyn Object>)> for Dict {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (String, Box<dyn Object>)>
+    {
+        let mut dict = Dict::new();
+        for (key, value) in iter {
+            dict.insert_box(&key, value);
+        }
+        dict
     }
 }
 
@@ -61,12 +280,45 @@ impl Object for Dict {
     fn type_name(&self) -> &'static str {
         "Dict"
     }
+
+    fn clone_box(&self) -> Result<Box<dyn Object>, InternalError> {
+        Ok(Box::new(self.try_clone()?))
+    }
+
+    fn fmt_display(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+
+    fn fmt_debug(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// JSON+ like form, `{"key": value, ...}`
+impl fmt::Display for Dict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{{")?;
+        for (index, (key, value)) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "\"{}\": {}", escape_string(key), value)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl fmt::Debug for Dict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Dict ")?;
+        f.debug_map().entries(self.iter()).finish()
+    }
 }
 
 impl Container<&str> for Dict {
     fn get_type_name(&self, key: &str) -> Result<&'static str, InternalError> {
         if self.has_key(key) {
-            Ok(self.get(key).unwrap().as_ref().type_name())
+            Ok(self.get(key).unwrap().type_name())
         } else {
             let msg = format!("Key \"{}\" not found in dictionary.", key);
             Err(InternalError::new(&msg, ErrorKind::KeyNotFound))
@@ -79,13 +331,13 @@ impl Container<&str> for Dict {
         TargetType: 'static + Object
     {
         if self.has_key(key) {
-            if self.get(key).unwrap().as_ref().as_any().is::<TargetType>() {
-                Ok(self.get(key).unwrap().as_ref().as_any()
+            if self.get(key).unwrap().as_any().is::<TargetType>() {
+                Ok(self.get(key).unwrap().as_any()
                        .downcast_ref::<TargetType>().unwrap())
             } else {
                 let msg = format!("Value at key \"{}\" is of type {}, failed \
                                   to match the requested type {}.",
-                                  key, self.get(key).unwrap().as_ref().type_name(),
+                                  key, self.get(key).unwrap().type_name(),
                                   std::any::type_name::<TargetType>());
                 Err(InternalError::new(&msg, ErrorKind::MismatchedType))
             }
@@ -101,13 +353,13 @@ impl Container<&str> for Dict {
         TargetType: 'static + Object
     {
         if self.has_key(key) {
-            if self.get(key).unwrap().as_ref().as_any().is::<TargetType>() {
+            if self.get(key).unwrap().as_any().is::<TargetType>() {
                 Ok(self.get_mut(key).unwrap().as_mut().as_mut_any()
                        .downcast_mut::<TargetType>().unwrap())
             } else {
                 let msg = format!("Value at key \"{}\" is of type {}, failed \
                                   to match the request type {}.",
-                                  key, self.get(key).unwrap().as_ref().type_name(),
+                                  key, self.get(key).unwrap().type_name(),
                                   std::any::type_name::<TargetType>());
                 Err(InternalError::new(&msg, ErrorKind::MismatchedType))
             }
@@ -126,7 +378,7 @@ impl Container<&str> for Dict {
             let msg = format!("Key \"{}\" already exists in dictionary.", key);
             Err(InternalError::new(&msg, ErrorKind::DuplicateKey))
         } else {
-            self.internal.insert(key.to_owned(), Box::new(obj));
+            self.insert(key, obj);
             Ok(self)
         }
     }
@@ -156,6 +408,38 @@ impl Container<&str> for Dict {
             Ok(self)
         }
     }
+
+    fn remove(&mut self, key: &str) -> Result<Box<dyn Object>, InternalError> {
+        match self.key_index.remove(key) {
+            Some(position) => {
+                let (_, value) = self.internal.remove(position);
+                // Only keys after the removed one shift forward by one.
+                for (key, _) in &self.internal[position..] {
+                    if let Some(index) = self.key_index.get_mut(key) {
+                        *index -= 1;
+                    }
+                }
+                Ok(value)
+            },
+            None => {
+                let msg = format!("Key \"{}\" not found in dictionary.", key);
+                Err(InternalError::new(&msg, ErrorKind::KeyNotFound))
+            }
+        }
+    }
+}
+
+/// Deep equality, see `equality::deep_eq`.
+impl PartialEq for Dict {
+    fn eq(&self, other: &Self) -> bool {
+        equality::deep_eq(self, other)
+    }
+}
+
+impl Hash for Dict {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        equality::deep_hash(self, state);
+    }
 }
 
 #[cfg(test)]
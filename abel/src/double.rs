@@ -2,12 +2,13 @@ use std::fmt;
 use std::any::Any;
 use std::ops::Deref;
 
+use crate::error::InternalError;
 use crate::object::Object;
 use crate::typefy::IntoType;
 use crate::marker::ScalarValued;
 use crate::integer::Integer;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Double {
     internal: f64,
 }
@@ -38,6 +39,19 @@ impl Object for Double {
     fn type_name(&self) -> &'static str {
         "Double"
     }
+
+    fn clone_box(&self) -> Result<Box<dyn Object>, InternalError> {
+        Ok(Box::new(self.clone()))
+    }
+
+    fn fmt_display(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Keeps a decimal point, as in JSON+ text
+        write!(f, "{:?}", self.internal)
+    }
+
+    fn fmt_debug(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
 }
 
 impl ScalarValued<f64> for Double {
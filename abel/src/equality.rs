@@ -0,0 +1,201 @@
+//! Deep structural equality and hashing of object trees.
+use std::hash::Hasher;
+
+use crate::object::Object;
+use crate::marker::ScalarValued;
+use crate::null::Null;
+use crate::bool::Bool;
+use crate::integer::Integer;
+use crate::double::Double;
+use crate::complex::Complex;
+use crate::text::Text;
+use crate::binary::Binary;
+use crate::bitstring::Bitstring;
+use crate::list::List;
+use crate::dict::Dict;
+
+/// How numbers of different types are compared.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumericEquality {
+    /// Numbers must be of the same type, `Integer(5) != Double(5.0)`.
+    Strict,
+    /// Integer, Double and Complex are compared by value, the same as
+    /// their `PartialEq` implementations, `Integer(5) == Double(5.0)`.
+    Lenient,
+}
+
+/// Hasher producing the same value for the same tree across runs,
+/// platforms and compiler versions (64-bit FNV-1a).
+pub struct StableHasher {
+    state: u64,
+}
+
+impl StableHasher {
+    pub fn new() -> Self {
+        StableHasher {
+            state: 0xcbf29ce484222325
+        }
+    }
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.state
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= *byte as u64;
+            self.state = self.state.wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+// Integer, Double and Complex as a complex value.
+fn numeric_value(obj: &dyn Object) -> Option<(f64, f64)> {
+    let any = obj.as_any();
+    if let Some(integer) = any.downcast_ref::<Integer>() {
+        Some((integer.value() as f64, 0.0))
+    } else if let Some(double) = any.downcast_ref::<Double>() {
+        Some((double.value(), 0.0))
+    } else {
+        any.downcast_ref::<Complex>().map(|complex| complex.value())
+    }
+}
+
+fn same_type_eq<T>(a: &dyn Object, b: &dyn Object) -> bool
+where
+    T: 'static + PartialEq
+{
+    match (a.as_any().downcast_ref::<T>(), b.as_any().downcast_ref::<T>()) {
+        (Some(left), Some(right)) => left == right,
+        _ => false,
+    }
+}
+
+fn dict_eq(a: &Dict, b: &Dict, policy: NumericEquality) -> bool {
+    a.len() == b.len()
+        && a.iter().all(|(key, value)| match b.get(key) {
+            Some(other) => deep_eq_with(value.as_ref(), other, policy),
+            None => false,
+        })
+}
+
+fn list_eq(a: &List, b: &List, policy: NumericEquality) -> bool {
+    a.len() == b.len()
+        && a.iter().zip(b.iter()).all(|(left, right)| {
+            deep_eq_with(left.as_ref(), right.as_ref(), policy)
+        })
+}
+
+/// Deep equality with the given numeric policy. Dictionaries are equal if
+/// they hold equal values under the same keys, regardless of key order.
+/// User-defined types are compared by `Object::equals`.
+pub fn deep_eq_with(a: &dyn Object, b: &dyn Object, policy: NumericEquality)
+-> bool
+{
+    if policy == NumericEquality::Lenient {
+        if let (Some(left), Some(right)) = (numeric_value(a), numeric_value(b)) {
+            return left == right;
+        }
+    }
+    if a.type_name() != b.type_name() {
+        return false;
+    }
+    match a.type_name() {
+        "Null" => same_type_eq::<Null>(a, b),
+        "Bool" => same_type_eq::<Bool>(a, b),
+        "Integer" => same_type_eq::<Integer>(a, b),
+        "Double" => same_type_eq::<Double>(a, b),
+        "Complex" => same_type_eq::<Complex>(a, b),
+        "Text" => same_type_eq::<Text>(a, b),
+        "Binary" => same_type_eq::<Binary>(a, b),
+        "Bitstring" => same_type_eq::<Bitstring>(a, b),
+        "Dict" => match (a.as_any().downcast_ref::<Dict>(),
+                         b.as_any().downcast_ref::<Dict>()) {
+            (Some(left), Some(right)) => dict_eq(left, right, policy),
+            _ => false,
+        },
+        "List" => match (a.as_any().downcast_ref::<List>(),
+                         b.as_any().downcast_ref::<List>()) {
+            (Some(left), Some(right)) => list_eq(left, right, policy),
+            _ => false,
+        },
+        _ => a.equals(b),
+    }
+}
+
+/// Deep equality with `NumericEquality::Lenient`.
+pub fn deep_eq(a: &dyn Object, b: &dyn Object) -> bool {
+    deep_eq_with(a, b, NumericEquality::Lenient)
+}
+
+fn write_tag<H: Hasher>(tag: &str, state: &mut H) {
+    state.write(tag.as_bytes());
+    state.write(&[0xff]);
+}
+
+fn write_f64<H: Hasher>(value: f64, state: &mut H) {
+    // +0.0 and -0.0 are equal, so must be their hashes.
+    let value = if value == 0.0 { 0.0 } else { value };
+    state.write(&value.to_bits().to_le_bytes());
+}
+
+/// Feeds a whole tree into a hasher. Equal trees, under either numeric
+/// policy, give equal hashes. User-defined types are hashed by
+/// `Object::hash_into`.
+pub fn deep_hash<H: Hasher>(obj: &dyn Object, state: &mut H) {
+    if let Some((real, imag)) = numeric_value(obj) {
+        write_tag("Number", state);
+        write_f64(real, state);
+        write_f64(imag, state);
+        return;
+    }
+    let any = obj.as_any();
+    write_tag(obj.type_name(), state);
+    if let Some(boolean) = any.downcast_ref::<Bool>() {
+        state.write(&[boolean.value() as u8]);
+    } else if let Some(text) = any.downcast_ref::<Text>() {
+        write_tag(text, state);
+    } else if let Some(binary) = any.downcast_ref::<Binary>() {
+        write_tag(binary, state);
+    } else if let Some(bitstring) = any.downcast_ref::<Bitstring>() {
+        write_tag(bitstring, state);
+    } else if let Some(list) = any.downcast_ref::<List>() {
+        state.write(&(list.len() as u64).to_le_bytes());
+        for item in list.iter() {
+            deep_hash(item.as_ref(), state);
+        }
+    } else if let Some(dict) = any.downcast_ref::<Dict>() {
+        // Entries are hashed separately and summed, since key order
+        // doesn't matter for equality.
+        let mut sum: u64 = 0;
+        for (key, value) in dict.iter() {
+            let mut entry_state = StableHasher::new();
+            write_tag(key, &mut entry_state);
+            deep_hash(value.as_ref(), &mut entry_state);
+            sum = sum.wrapping_add(entry_state.finish());
+        }
+        state.write(&(dict.len() as u64).to_le_bytes());
+        state.write(&sum.to_le_bytes());
+    } else if !any.is::<Null>() {
+        obj.hash_into(state);
+    }
+}
+
+/// Stable hash of a whole tree, see `StableHasher`.
+pub fn stable_hash(obj: &dyn Object) -> u64 {
+    let mut state = StableHasher::new();
+    deep_hash(obj, &mut state);
+    state.finish()
+}
+
+#[cfg(test)]
+#[path = "./unittest/equality/tests.rs"]
+mod tests;
@@ -1,6 +1,9 @@
 use std::fmt;
+use std::io;
 
-#[derive(Debug, PartialEq)]
+use crate::span::Span;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ErrorKind {
     IncompatibleType,
     IndexOutOfRange,
@@ -15,6 +18,8 @@ pub enum ErrorKind {
     InvalidInput,
     FailedToIdentify,
     FileNotFound,
+    FailedToWrite,
+    FailedToRead,
 }
  
 impl ErrorKind {
@@ -37,6 +42,8 @@ impl ErrorKind {
             ErrorKind::InvalidInput => "INVALID_INPUT",
             ErrorKind::FailedToIdentify => "FAILED_TO_IDENTIFY",
             ErrorKind::FileNotFound => "FILE_NOT_FOUND",
+            ErrorKind::FailedToWrite => "FAILED_TO_WRITE",
+            ErrorKind::FailedToRead => "FAILED_TO_READ",
         }
     }
 }
@@ -98,6 +105,10 @@ impl InternalError {
     pub fn get_header(&self) -> &'static str {
         self.error_kind.get_header()
     }
+
+    pub fn get_kind(&self) -> ErrorKind {
+        self.error_kind
+    }
 }
 
 impl fmt::Display for InternalError {
@@ -106,11 +117,15 @@ impl fmt::Display for InternalError {
     }
 }
 
+impl std::error::Error for InternalError {}
+
 /// Parser error
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ParserError {
     message: String,
     line_number: i32,
+    span: Option<Span>,
+    hint: Option<String>,
     error_kind: ErrorKind,
 }
 
@@ -119,7 +134,9 @@ impl Error for ParserError {
         ParserError {
             message: msg.to_string(),
             line_number: -99, // default to -99
-            error_kind: error_kind,
+            span: None,
+            hint: None,
+            error_kind,
         }
     }
     
@@ -154,9 +171,86 @@ impl ParserError {
         self.line_number
     }
 
+    /// Sets the source text in error, the line as well.
+    pub fn set_span(&mut self, span: Span) {
+        self.line_number = span.start.line;
+        self.span = Some(span);
+    }
+
+    pub fn get_span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Suggestion on how to fix the error, shown by `render`.
+    pub fn set_hint(&mut self, hint: &str) {
+        self.hint = Some(hint.to_string());
+    }
+
+    pub fn get_hint(&self) -> Option<&str> {
+        self.hint.as_deref()
+    }
+
+    /// Compiler-style report of the error in the given source text, e.g.
+    ///
+    /// ```text
+    /// error[SYNTAX_ERROR]: Comma is meaningless.
+    ///  --> line 2, column 10
+    ///   |
+    /// 2 |   "A": 1,,
+    ///   |          ^
+    ///   = hint: Remove the extra comma.
+    /// ```
+    ///
+    /// Columns are counted from 1 in the report.
+    pub fn render(&self, source: &str) -> String {
+        let mut report = format!("error[{}]: {}\n", self.get_header(), self.get_msg());
+        let line_number = self.get_line();
+        let source_line = if line_number > 0 {
+            source.lines().nth(line_number as usize - 1)
+        } else {
+            None
+        };
+        let gutter = " ".repeat(line_number.max(0).to_string().len());
+        match (source_line, self.span) {
+            (Some(source_line), Some(span)) => {
+                report.push_str(&format!("{} --> line {}, column {}\n",
+                                         gutter, line_number, span.start.column + 1));
+                report.push_str(&format!("{} |\n", gutter));
+                report.push_str(&format!("{} | {}\n", line_number, source_line));
+                // Tabs are kept so that the caret lines up with the source.
+                let padding: String = source_line.chars()
+                    .take(span.start.column.max(0) as usize)
+                    .map(|ch| if ch == '\t' { '\t' } else { ' ' })
+                    .collect();
+                let line_width = source_line.chars().count() as i32;
+                let end_column = if span.end.line == span.start.line {
+                    span.end.column
+                } else {
+                    line_width
+                };
+                let width = (end_column - span.start.column).max(1) as usize;
+                report.push_str(&format!("{} | {}{}\n", gutter, padding, "^".repeat(width)));
+            },
+            (Some(source_line), None) => {
+                report.push_str(&format!("{} --> line {}\n", gutter, line_number));
+                report.push_str(&format!("{} |\n", gutter));
+                report.push_str(&format!("{} | {}\n", line_number, source_line));
+            },
+            _ => {},
+        }
+        if let Some(hint) = &self.hint {
+            report.push_str(&format!("{} = hint: {}\n", gutter, hint));
+        }
+        report
+    }
+
     pub fn get_header(&self) -> &'static str {
         self.error_kind.get_header()
     }
+
+    pub fn get_kind(&self) -> ErrorKind {
+        self.error_kind
+    }
 }
 
 impl fmt::Display for ParserError {
@@ -165,6 +259,160 @@ impl fmt::Display for ParserError {
     }
 }
 
+impl std::error::Error for ParserError {}
+
+/// Error of a typed conversion, located by the path of the failing value,
+/// e.g. `servers[2].port`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldError {
+    message: String,
+    path: String,
+    error_kind: ErrorKind,
+}
+
+impl Error for FieldError {
+    fn new(msg: &str, error_kind: ErrorKind) -> Self {
+        FieldError {
+            message: msg.to_string(),
+            path: String::new(),
+            error_kind
+        }
+    }
+
+    fn set_msg(&mut self, msg: &str) {
+        self.message = msg.to_string();
+    }
+
+    fn get_msg(&self) -> &str {
+        &self.message
+    }
+
+    fn full_message(&self) -> String {
+        if self.path.is_empty() {
+            format!("{}({})", self.get_header(), self.get_msg())
+        } else {
+            format!("{}({}: {})", self.get_header(), self.path, self.get_msg())
+        }
+    }
+}
+
+impl FieldError {
+    /// Prepends a dictionary key to the path.
+    pub fn with_key(mut self, key: &str) -> Self {
+        self.path = if self.path.is_empty() || self.path.starts_with('[') {
+            format!("{}{}", key, self.path)
+        } else {
+            format!("{}.{}", key, self.path)
+        };
+        self
+    }
+
+    /// Prepends a list index to the path.
+    pub fn with_index(mut self, index: usize) -> Self {
+        self.path = if self.path.is_empty() || self.path.starts_with('[') {
+            format!("[{}]{}", index, self.path)
+        } else {
+            format!("[{}].{}", index, self.path)
+        };
+        self
+    }
+
+    /// Path of the failing value, empty for the value converted itself.
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn get_header(&self) -> &'static str {
+        self.error_kind.get_header()
+    }
+
+    pub fn get_kind(&self) -> ErrorKind {
+        self.error_kind
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.full_message())
+    }
+}
+
+impl std::error::Error for FieldError {}
+
+/// Any error raised by the crate. Parser and loader errors are `Parser`,
+/// container errors are `Internal`, typed conversion errors are `Field` and
+/// failures to open or read input are `Io`.
+#[derive(Debug)]
+pub enum AbelError {
+    Parser(ParserError),
+    Internal(InternalError),
+    Field(FieldError),
+    Io(io::Error),
+}
+
+impl AbelError {
+    /// Kind of the wrapped error. I/O errors are `FileNotFound` or
+    /// `FailedToRead`.
+    pub fn get_kind(&self) -> ErrorKind {
+        match self {
+            AbelError::Parser(error) => error.get_kind(),
+            AbelError::Internal(error) => error.get_kind(),
+            AbelError::Field(error) => error.get_kind(),
+            AbelError::Io(error) if error.kind() == io::ErrorKind::NotFound => {
+                ErrorKind::FileNotFound
+            },
+            AbelError::Io(_) => ErrorKind::FailedToRead,
+        }
+    }
+}
+
+// The wrapped error is the source, so it isn't repeated here.
+impl fmt::Display for AbelError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AbelError::Parser(_) => write!(f, "failed to parse input"),
+            AbelError::Internal(_) => write!(f, "container operation failed"),
+            AbelError::Field(_) => write!(f, "typed conversion failed"),
+            AbelError::Io(_) => write!(f, "failed to read input"),
+        }
+    }
+}
+
+impl std::error::Error for AbelError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AbelError::Parser(error) => Some(error),
+            AbelError::Internal(error) => Some(error),
+            AbelError::Field(error) => Some(error),
+            AbelError::Io(error) => Some(error),
+        }
+    }
+}
+
+impl From<ParserError> for AbelError {
+    fn from(error: ParserError) -> Self {
+        AbelError::Parser(error)
+    }
+}
+
+impl From<InternalError> for AbelError {
+    fn from(error: InternalError) -> Self {
+        AbelError::Internal(error)
+    }
+}
+
+impl From<FieldError> for AbelError {
+    fn from(error: FieldError) -> Self {
+        AbelError::Field(error)
+    }
+}
+
+impl From<io::Error> for AbelError {
+    fn from(error: io::Error) -> Self {
+        AbelError::Io(error)
+    }
+}
+
 // Unittest
 #[cfg(test)]
 #[path = "./unittest/error/tests.rs"]
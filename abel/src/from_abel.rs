@@ -0,0 +1,204 @@
+//! Typed conversion from abel objects into Rust values, the reverse of
+//! `IntoType`.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::error::{Error, ErrorKind, FieldError};
+use crate::object::Object;
+use crate::null::Null;
+use crate::bool::Bool;
+use crate::integer::Integer;
+use crate::double::Double;
+use crate::text::Text;
+use crate::list::List;
+use crate::dict::Dict;
+
+pub trait FromAbel: Sized {
+    fn from_abel(obj: &dyn Object) -> Result<Self, FieldError>;
+
+    /// Value taken when a dictionary key is absent. Only `Option` has one.
+    fn from_missing() -> Option<Self> {
+        None
+    }
+}
+
+/// Converts a dictionary, typically into a struct implementing `FromAbel`.
+pub fn from_dict<T: FromAbel>(dict: &Dict) -> Result<T, FieldError> {
+    T::from_abel(dict)
+}
+
+pub fn from_list<T: FromAbel>(list: &List) -> Result<T, FieldError> {
+    T::from_abel(list)
+}
+
+/// Converts the value of a key, for use in `FromAbel` implementations of
+/// structs. Errors are located under the key.
+pub fn get_field<T: FromAbel>(dict: &Dict, key: &str) -> Result<T, FieldError> {
+    match dict.get(key) {
+        Some(value) => T::from_abel(value).map_err(|error| error.with_key(key)),
+        None => T::from_missing().ok_or_else(|| {
+            FieldError::new("Key is not found.", ErrorKind::KeyNotFound).with_key(key)
+        }),
+    }
+}
+
+/// Like `get_field`, with a fallback for an absent key.
+pub fn get_field_or_else<T, F>(dict: &Dict, key: &str, default: F) -> Result<T, FieldError>
+where
+    T: FromAbel,
+    F: FnOnce() -> T
+{
+    match dict.get(key) {
+        Some(value) => T::from_abel(value).map_err(|error| error.with_key(key)),
+        None => Ok(default()),
+    }
+}
+
+fn mismatch(expected: &str, obj: &dyn Object) -> FieldError {
+    let msg = format!("expected {}, found {}", expected, obj.type_name());
+    FieldError::new(&msg, ErrorKind::MismatchedType)
+}
+
+fn downcast<'a, T: 'static>(obj: &'a dyn Object, expected: &str) -> Result<&'a T, FieldError> {
+    obj.as_any().downcast_ref::<T>().ok_or_else(|| mismatch(expected, obj))
+}
+
+pub fn expect_dict(obj: &dyn Object) -> Result<&Dict, FieldError> {
+    downcast::<Dict>(obj, "Dict")
+}
+
+pub fn expect_list(obj: &dyn Object) -> Result<&List, FieldError> {
+    downcast::<List>(obj, "List")
+}
+
+impl FromAbel for bool {
+    fn from_abel(obj: &dyn Object) -> Result<Self, FieldError> {
+        Ok(**downcast::<Bool>(obj, "Bool")?)
+    }
+}
+
+impl FromAbel for String {
+    fn from_abel(obj: &dyn Object) -> Result<Self, FieldError> {
+        Ok(downcast::<Text>(obj, "Text")?.to_string())
+    }
+}
+
+impl FromAbel for f64 {
+    fn from_abel(obj: &dyn Object) -> Result<Self, FieldError> {
+        if let Some(integer) = obj.as_any().downcast_ref::<Integer>() {
+            return Ok(**integer as f64);
+        }
+        Ok(**downcast::<Double>(obj, "Double")?)
+    }
+}
+
+impl FromAbel for f32 {
+    fn from_abel(obj: &dyn Object) -> Result<Self, FieldError> {
+        Ok(f64::from_abel(obj)? as f32)
+    }
+}
+
+// Standard JSON has no integers, so integral doubles are accepted too.
+macro_rules! impl_from_abel_for_integer {
+    ($($prim:ty),*) => {
+        $(
+            impl FromAbel for $prim {
+                fn from_abel(obj: &dyn Object) -> Result<Self, FieldError> {
+                    let out_of_range = || {
+                        let msg = format!("{} is out of range of {}",
+                                          obj.to_string(), stringify!($prim));
+                        FieldError::new(&msg, ErrorKind::IncompatibleType)
+                    };
+                    if let Some(integer) = obj.as_any().downcast_ref::<Integer>() {
+                        return <$prim>::try_from(**integer).map_err(|_| out_of_range());
+                    }
+                    match obj.as_any().downcast_ref::<Double>() {
+                        Some(double) if double.fract() == 0.0 => {
+                            // `MAX as f64` rounds up to 2^63 for i64 and 2^64 for u64,
+                            // so compare against the exact exclusive bound instead.
+                            let value_bits = <$prim>::BITS - (<$prim>::MIN != 0) as u32;
+                            let upper = 2f64.powi(value_bits as i32);
+                            if **double < <$prim>::MIN as f64 || **double >= upper {
+                                return Err(out_of_range());
+                            }
+                            Ok(**double as $prim)
+                        },
+                        _ => Err(mismatch("Integer", obj)),
+                    }
+                }
+            }
+        )*
+    }
+}
+
+impl_from_abel_for_integer!(i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+/// `None` for null and absent keys.
+impl<T: FromAbel> FromAbel for Option<T> {
+    fn from_abel(obj: &dyn Object) -> Result<Self, FieldError> {
+        if obj.as_any().is::<Null>() {
+            return Ok(None);
+        }
+        T::from_abel(obj).map(Some)
+    }
+
+    fn from_missing() -> Option<Self> {
+        Some(None)
+    }
+}
+
+impl<T: FromAbel> FromAbel for Vec<T> {
+    fn from_abel(obj: &dyn Object) -> Result<Self, FieldError> {
+        expect_list(obj)?.iter().enumerate()
+            .map(|(index, element)| {
+                T::from_abel(element.as_ref()).map_err(|error| error.with_index(index))
+            })
+            .collect()
+    }
+}
+
+impl<K, T> FromAbel for HashMap<K, T>
+where
+    K: From<String> + Eq + Hash,
+    T: FromAbel
+{
+    fn from_abel(obj: &dyn Object) -> Result<Self, FieldError> {
+        expect_dict(obj)?.iter()
+            .map(|(key, value)| {
+                let value = T::from_abel(value.as_ref()).map_err(|error| error.with_key(key))?;
+                Ok((K::from(key.clone()), value))
+            })
+            .collect()
+    }
+}
+
+// Tuples are read from lists of the same length.
+macro_rules! impl_from_abel_for_tuple {
+    ($len:expr; $($name:ident: $index:tt),*) => {
+        impl<$($name: FromAbel),*> FromAbel for ($($name,)*) {
+            fn from_abel(obj: &dyn Object) -> Result<Self, FieldError> {
+                let list = expect_list(obj)?;
+                if list.len() != $len {
+                    let msg = format!("expected List of length {}, found length {}",
+                                      $len, list.len());
+                    return Err(FieldError::new(&msg, ErrorKind::MismatchedType));
+                }
+                Ok(($(
+                    $name::from_abel(list[$index].as_ref())
+                        .map_err(|error| error.with_index($index))?,
+                )*))
+            }
+        }
+    }
+}
+
+impl_from_abel_for_tuple!(1; A: 0);
+impl_from_abel_for_tuple!(2; A: 0, B: 1);
+impl_from_abel_for_tuple!(3; A: 0, B: 1, C: 2);
+impl_from_abel_for_tuple!(4; A: 0, B: 1, C: 2, D: 3);
+
+// Unittest
+#[cfg(test)]
+#[path = "./unittest/from_abel/tests.rs"]
+mod tests;
@@ -2,11 +2,12 @@ use std::fmt;
 use std::any::Any;
 use std::ops::Deref;
 
+use crate::error::InternalError;
 use crate::object::Object;
 use crate::typefy::IntoType;
 use crate::marker::ScalarValued;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Integer {
     internal: i32,
 }
@@ -37,6 +38,18 @@ impl Object for Integer {
     fn type_name(&self) -> &'static str {
         "Integer"
     }
+
+    fn clone_box(&self) -> Result<Box<dyn Object>, InternalError> {
+        Ok(Box::new(self.clone()))
+    }
+
+    fn fmt_display(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+
+    fn fmt_debug(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
 }
 
 impl ScalarValued<i32> for Integer {
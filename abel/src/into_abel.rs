@@ -0,0 +1,161 @@
+//! Typed conversion from Rust values into abel objects, the reverse of
+//! `FromAbel`.
+
+use std::collections::HashMap;
+use std::mem;
+
+use crate::error::{Error, ErrorKind, FieldError};
+use crate::object::Object;
+use crate::null::Null;
+use crate::bool::Bool;
+use crate::integer::Integer;
+use crate::double::Double;
+use crate::text::Text;
+use crate::list::List;
+use crate::dict::Dict;
+
+pub trait IntoAbel {
+    fn to_abel(&self) -> Box<dyn Object>;
+}
+
+/// Values always converted into a dictionary: structs with named fields,
+/// internally tagged enums and maps. Only these can be flattened.
+pub trait IntoAbelDict: IntoAbel {
+    fn to_abel_dict(&self) -> Dict;
+}
+
+/// Converts a value into a dictionary, typically a struct implementing
+/// `IntoAbel`.
+pub fn to_dict<T: IntoAbel + ?Sized>(value: &T) -> Result<Dict, FieldError> {
+    let mut obj = value.to_abel();
+    let type_name = obj.type_name();
+    match obj.as_mut_any().downcast_mut::<Dict>() {
+        Some(dict) => Ok(mem::take(dict)),
+        None => {
+            let msg = format!("expected Dict, found {}", type_name);
+            Err(FieldError::new(&msg, ErrorKind::MismatchedType))
+        }
+    }
+}
+
+/// Moves the entries of a value converted into a dictionary into `dict`,
+/// used for flattened fields.
+pub fn flatten_into<T: IntoAbelDict + ?Sized>(dict: &mut Dict, value: &T) {
+    for (key, value) in value.to_abel_dict() {
+        dict.insert_box(&key, value);
+    }
+}
+
+impl IntoAbel for bool {
+    fn to_abel(&self) -> Box<dyn Object> {
+        Box::new(Bool::new(*self))
+    }
+}
+
+impl IntoAbel for str {
+    fn to_abel(&self) -> Box<dyn Object> {
+        Box::new(Text::new(self))
+    }
+}
+
+impl IntoAbel for String {
+    fn to_abel(&self) -> Box<dyn Object> {
+        Box::new(Text::new(self))
+    }
+}
+
+impl IntoAbel for f64 {
+    fn to_abel(&self) -> Box<dyn Object> {
+        Box::new(Double::new(*self))
+    }
+}
+
+impl IntoAbel for f32 {
+    fn to_abel(&self) -> Box<dyn Object> {
+        Box::new(Double::new(*self as f64))
+    }
+}
+
+// Integers that don't fit into `Integer` become doubles.
+macro_rules! impl_into_abel_for_integer {
+    ($($prim:ty),*) => {
+        $(
+            impl IntoAbel for $prim {
+                fn to_abel(&self) -> Box<dyn Object> {
+                    match i32::try_from(*self) {
+                        Ok(value) => Box::new(Integer::new(value)),
+                        Err(_) => Box::new(Double::new(*self as f64)),
+                    }
+                }
+            }
+        )*
+    }
+}
+
+impl_into_abel_for_integer!(i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+/// `None` becomes null.
+impl<T: IntoAbel> IntoAbel for Option<T> {
+    fn to_abel(&self) -> Box<dyn Object> {
+        match self {
+            Some(value) => value.to_abel(),
+            None => Box::new(Null::new()),
+        }
+    }
+}
+
+impl<T: IntoAbel> IntoAbel for Vec<T> {
+    fn to_abel(&self) -> Box<dyn Object> {
+        Box::new(self.iter().map(|element| element.to_abel()).collect::<List>())
+    }
+}
+
+/// Keys are sorted, so that the output doesn't depend on hashing.
+impl<K, T> IntoAbel for HashMap<K, T>
+where
+    K: AsRef<str>,
+    T: IntoAbel
+{
+    fn to_abel(&self) -> Box<dyn Object> {
+        Box::new(self.to_abel_dict())
+    }
+}
+
+impl<K, T> IntoAbelDict for HashMap<K, T>
+where
+    K: AsRef<str>,
+    T: IntoAbel
+{
+    fn to_abel_dict(&self) -> Dict {
+        let mut entries: Vec<(&K, &T)> = self.iter().collect();
+        entries.sort_by(|a, b| a.0.as_ref().cmp(b.0.as_ref()));
+        let mut dict = Dict::new();
+        for (key, value) in entries {
+            dict.insert_box(key.as_ref(), value.to_abel());
+        }
+        dict
+    }
+}
+
+// Tuples are written as lists.
+macro_rules! impl_into_abel_for_tuple {
+    ($($name:ident: $index:tt),*) => {
+        impl<$($name: IntoAbel),*> IntoAbel for ($($name,)*) {
+            fn to_abel(&self) -> Box<dyn Object> {
+                let mut list = List::new();
+                $(list.push_box(self.$index.to_abel());)*
+                Box::new(list)
+            }
+        }
+    }
+}
+
+impl_into_abel_for_tuple!(A: 0);
+impl_into_abel_for_tuple!(A: 0, B: 1);
+impl_into_abel_for_tuple!(A: 0, B: 1, C: 2);
+impl_into_abel_for_tuple!(A: 0, B: 1, C: 2, D: 3);
+
+// Unittest
+#[cfg(test)]
+#[path = "./unittest/into_abel/tests.rs"]
+mod tests;
@@ -1,3 +1,7 @@
+use std::io::Read;
+
+use crate::error::{AbelError, Error, ErrorKind, InternalError, ParserError};
+use crate::object::Object;
 use crate::text::Text;
 use crate::container::Container;
 use crate::list::List;
@@ -5,9 +9,63 @@ use crate::dict::Dict;
 
 use crate::util::get_closing_symbol_by_opening;
 use crate::converter;
+use crate::token::Token;
 use crate::json_token::{JsonTokenType, JsonToken, JsonContainerType, JsonTerminalType};
 use crate::json_parser::JsonParser;
 
+/// Root container of a loaded document, owned by the caller.
+#[derive(Debug, PartialEq)]
+pub enum JsonRoot {
+    Dict(Dict),
+    List(List),
+    /// A document of a single null, bool, number or string.
+    Scalar(Box<dyn Object>),
+}
+
+impl JsonRoot {
+    pub fn as_dict(&self) -> Option<&Dict> {
+        match self {
+            JsonRoot::Dict(dict) => Some(dict),
+            _ => None,
+        }
+    }
+
+    pub fn as_list(&self) -> Option<&List> {
+        match self {
+            JsonRoot::List(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    pub fn into_dict(self) -> Option<Dict> {
+        match self {
+            JsonRoot::Dict(dict) => Some(dict),
+            _ => None,
+        }
+    }
+
+    pub fn into_list(self) -> Option<List> {
+        match self {
+            JsonRoot::List(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    pub fn as_scalar(&self) -> Option<&dyn Object> {
+        match self {
+            JsonRoot::Scalar(scalar) => Some(scalar.as_ref()),
+            _ => None,
+        }
+    }
+
+    pub fn into_scalar(self) -> Option<Box<dyn Object>> {
+        match self {
+            JsonRoot::Scalar(scalar) => Some(scalar),
+            _ => None,
+        }
+    }
+}
+
 pub struct JsonLoader {
     root_container_type: JsonContainerType,
     current_index: usize,
@@ -23,36 +81,67 @@ impl JsonLoader {
         let opening_sym = opening_token.literal.chars().collect::<Vec<char>>()[0];
         let closing_sym = closing_token.literal.chars().collect::<Vec<char>>()[0];
         // That get_closing function returns Result<char, Error>
-        closing_sym == get_closing_symbol_by_opening(opening_sym).unwrap()
-            && closing_token.level == opening_token.level
-            && closing_token.parent_key == opening_token.parent_key
+        match get_closing_symbol_by_opening(opening_sym) {
+            Ok(expected_sym) => closing_sym == expected_sym
+                && closing_token.level == opening_token.level
+                && closing_token.parent_key == opening_token.parent_key,
+            Err(_) => false,
+        }
+    }
+
+    /// Error at the source text of a token.
+    fn token_error(&self, msg: &str, error_kind: ErrorKind, token: &JsonToken)
+    -> ParserError
+    {
+        let mut error = ParserError::new(msg, error_kind);
+        error.set_span(token.span);
+        error
     }
 
-    fn make_root_dict(&mut self, token_vector: &Vec<JsonToken>) -> Box<Dict> {
+    /// Converts a container error into a parser error at a token.
+    fn container_error(&self, error: InternalError, token: &JsonToken)
+    -> ParserError
+    {
+        self.token_error(error.get_msg(), error.get_kind(), token)
+    }
+
+    fn unexpected_token(&self, token: &JsonToken) -> ParserError {
+        let msg = format!("Unexpected {} token '{}'.",
+                          token.type_name(), token.literal);
+        self.token_error(&msg, ErrorKind::SyntaxError, token)
+    }
+
+    fn make_root_dict(&mut self, token_vector: &[JsonToken])
+    -> Result<Box<Dict>, ParserError>
+    {
         let mut dict_boxed = Box::new(Dict::new());
         while self.current_index < token_vector.len() {
             if token_vector[self.current_index].get_type() == JsonTokenType::Key {
-                self.at_key(&mut dict_boxed, token_vector);
+                self.at_key(&mut dict_boxed, token_vector)?;
                 continue;
             }
+            return Err(self.unexpected_token(&token_vector[self.current_index]));
         }
-        return dict_boxed;
+        Ok(dict_boxed)
     }
 
-    fn make_root_list(&mut self, token_vector: &Vec<JsonToken>) -> Box<List> {
+    fn make_root_list(&mut self, token_vector: &[JsonToken])
+    -> Result<Box<List>, ParserError>
+    {
         let mut list_boxed = Box::new(List::new());
         while self.current_index < token_vector.len() {
             if token_vector[self.current_index].get_type() == JsonTokenType::IterKey {
-                self.at_iter_key(&mut list_boxed, token_vector);
+                self.at_iter_key(&mut list_boxed, token_vector)?;
                 continue;
             }
+            return Err(self.unexpected_token(&token_vector[self.current_index]));
         }
-        return list_boxed;
+        Ok(list_boxed)
     }
 
     fn make_list(&mut self, index_opening_token: usize,
-                 token_vector: &Vec<JsonToken>)
-    -> Box<List>
+                 token_vector: &[JsonToken])
+    -> Result<Box<List>, ParserError>
     {
         let mut list_boxed = Box::new(List::new());
         self.current_index = index_opening_token + 1;
@@ -62,16 +151,18 @@ impl JsonLoader {
                     &token_vector[index_opening_token])
         {
             if token_vector[self.current_index].get_type() == JsonTokenType::IterKey {
-                self.at_iter_key(&mut list_boxed, token_vector);
+                self.at_iter_key(&mut list_boxed, token_vector)?;
                 continue;
             }
+            return Err(self.unexpected_token(&token_vector[self.current_index]));
         }
         self.current_index += 1;
-        return list_boxed;
+        Ok(list_boxed)
     }
 
     fn make_dict(&mut self, index_opening_token: usize,
-                 token_vector: &Vec<JsonToken>) -> Box<Dict>
+                 token_vector: &[JsonToken])
+    -> Result<Box<Dict>, ParserError>
     {
         let mut dict_boxed = Box::new(Dict::new());
         // Prepare current index for iteration. Iteration start from next index.
@@ -82,179 +173,315 @@ impl JsonLoader {
                     &token_vector[index_opening_token])
         {
             if token_vector[self.current_index].get_type() == JsonTokenType::Key {
-                self.at_key(&mut dict_boxed, token_vector);
+                self.at_key(&mut dict_boxed, token_vector)?;
                 continue;
             }
+            return Err(self.unexpected_token(&token_vector[self.current_index]));
         }
         // Since when current index is pointing at a dict closing symbole, while
         // loop terminates, must update current index for the next root-level run.
         self.current_index += 1;
-        return dict_boxed;
+        Ok(dict_boxed)
     }
 
     /// For standard JSON
     fn put_into<ContainerT, KeyT>(&mut self, boxed_ref: &mut Box<ContainerT>,
                                   key: KeyT, token: &JsonToken)
+    -> Result<(), ParserError>
     where
         ContainerT: Container<KeyT>,
     {
         let value = token.literal.clone();
-        if token.terminal_type == JsonTerminalType::Null {
-            boxed_ref.set(key, converter::as_null(&value).unwrap()).unwrap();
+        let at_token = |mut error: ParserError| {
+            error.set_span(token.span);
+            error
+        };
+        let result = if token.terminal_type == JsonTerminalType::Null {
+            boxed_ref.set(key, converter::as_null(&value).map_err(at_token)?)
         } else if token.terminal_type == JsonTerminalType::Bool {
-            boxed_ref.set(key, converter::as_bool(&value).unwrap()).unwrap();
+            boxed_ref.set(key, converter::as_bool(&value).map_err(at_token)?)
         } else if token.terminal_type == JsonTerminalType::Double {
-            boxed_ref.set(key, converter::as_double(&value).unwrap()).unwrap();
+            boxed_ref.set(key, converter::as_double(&value).map_err(at_token)?)
         } else { // set item as Text
-            boxed_ref.set(key, Text::new(&value)).unwrap();
+            boxed_ref.set(key, Text::new(&value))
+        };
+        match result {
+            Ok(_) => Ok(()),
+            Err(error) => Err(self.container_error(error, token)),
         }
     }
 
     /// For JSON+ format
     fn put_plus_into<ContainerT, KeyT>(&mut self, boxed_ref: &mut Box<ContainerT>,
                                        key: KeyT, token: &JsonToken)
+    -> Result<(), ParserError>
     where
         ContainerT: Container<KeyT>,
     {
         let value = token.literal.clone();
-        if token.terminal_type == JsonTerminalType::Null {
-            boxed_ref.set(key, converter::as_null(&value).unwrap()).unwrap();
+        let at_token = |mut error: ParserError| {
+            error.set_span(token.span);
+            error
+        };
+        let result = if token.terminal_type == JsonTerminalType::Null {
+            boxed_ref.set(key, converter::as_null(&value).map_err(at_token)?)
         } else if token.terminal_type == JsonTerminalType::Bool {
-            boxed_ref.set(key, converter::as_bool(&value).unwrap()).unwrap();
+            boxed_ref.set(key, converter::as_bool(&value).map_err(at_token)?)
         } else if token.terminal_type == JsonTerminalType::Integer {
-            boxed_ref.set(key, converter::as_integer(&value).unwrap()).unwrap();
+            boxed_ref.set(key, converter::as_integer(&value).map_err(at_token)?)
         } else if token.terminal_type == JsonTerminalType::Double {
-            boxed_ref.set(key, converter::as_double(&value).unwrap()).unwrap();
+            boxed_ref.set(key, converter::as_double(&value).map_err(at_token)?)
         } else if token.terminal_type == JsonTerminalType::Complex {
-            boxed_ref.set(key, converter::as_complex(&value).unwrap()).unwrap();
+            boxed_ref.set(key, converter::as_complex(&value).map_err(at_token)?)
         } else if token.terminal_type == JsonTerminalType::Binary {
-            boxed_ref.set(key, converter::as_binary(&value).unwrap()).unwrap();
+            boxed_ref.set(key, converter::as_binary(&value).map_err(at_token)?)
         } else if token.terminal_type == JsonTerminalType::Bitstring {
-            boxed_ref.set(key, converter::as_bitstring(&value).unwrap()).unwrap();
+            boxed_ref.set(key, converter::as_bitstring(&value).map_err(at_token)?)
         } else { // set item as Text
-            boxed_ref.set(key, Text::new(&value)).unwrap();
+            boxed_ref.set(key, Text::new(&value))
+        };
+        match result {
+            Ok(_) => Ok(()),
+            Err(error) => Err(self.container_error(error, token)),
+        }
+    }
+
+    /// Token following a key or an iter key, which holds its value.
+    fn value_token<'a>(&self, token_vector: &'a [JsonToken])
+    -> Result<&'a JsonToken, ParserError>
+    {
+        match token_vector.get(self.current_index + 1) {
+            Some(token) => Ok(token),
+            None => {
+                let key_token = &token_vector[self.current_index];
+                let msg = format!("Key '{}' has no value.", key_token.literal);
+                Err(self.token_error(&msg, ErrorKind::SyntaxError, key_token))
+            }
         }
     }
 
-    // TOOD
     fn fill_list(&mut self, list_boxed_ref: &mut Box<List>, iter_key: usize,
-                 token_vector: &Vec<JsonToken>)
+                 token_vector: &[JsonToken])
+    -> Result<(), ParserError>
     {
-         // Case 1, next token is a terminal
-         if token_vector[self.current_index + 1].get_type()
-                 == JsonTokenType::Terminal
-        {
+        let value_token = self.value_token(token_vector)?;
+        // Case 1, next token is a terminal
+        if value_token.get_type() == JsonTokenType::Terminal {
             // set terminal value
-            let token = &token_vector[self.current_index + 1];
             if self.parser_type == "json" {
-                self.put_into(list_boxed_ref, iter_key, token);
+                self.put_into(list_boxed_ref, iter_key, value_token)?;
             } else {
-                self.put_plus_into(list_boxed_ref, iter_key, token);
+                self.put_plus_into(list_boxed_ref, iter_key, value_token)?;
             }
             // shift current index to point at next iter key token
             self.current_index += 2;
         // Case 2, next token is dict opening.
-        } else if token_vector[self.current_index + 1].get_type()
-                == JsonTokenType::DictOpening
-        {
+        } else if value_token.get_type() == JsonTokenType::DictOpening {
             // build a dict recursively
-            let subdict_boxed = self.make_dict(self.current_index + 1, token_vector);
+            let subdict_boxed = self.make_dict(self.current_index + 1, token_vector)?;
             // set item: insert a dict into list
-            list_boxed_ref.insert_box(iter_key, subdict_boxed);
+            if let Err(error) = list_boxed_ref.set_box(iter_key, subdict_boxed) {
+                return Err(self.container_error(error, value_token));
+            }
         // Case 3, next token is list opening.
-        } else if token_vector[self.current_index + 1].get_type()
-                == JsonTokenType::ListOpening
-        {
+        } else if value_token.get_type() == JsonTokenType::ListOpening {
             // build a list
-            let sublist_boxed = self.make_list(self.current_index + 1, token_vector);
+            let sublist_boxed = self.make_list(self.current_index + 1, token_vector)?;
             // set item: insert a list into list
-            list_boxed_ref.insert_box(iter_key, sublist_boxed);
-        } else { /* TODO */ }
+            if let Err(error) = list_boxed_ref.set_box(iter_key, sublist_boxed) {
+                return Err(self.container_error(error, value_token));
+            }
+        } else {
+            return Err(self.unexpected_token(value_token));
+        }
+        Ok(())
     }
 
     fn fill_dict(&mut self, dict_boxed_ref: &mut Box<Dict>, key: &str,
-                 token_vector: & Vec<JsonToken>)
+                 token_vector: &[JsonToken])
+    -> Result<(), ParserError>
     {
+        let value_token = self.value_token(token_vector)?;
         // Case 1, next token is a terminal
-        if token_vector[self.current_index + 1].get_type()
-                == JsonTokenType::Terminal
-        {
+        if value_token.get_type() == JsonTokenType::Terminal {
             // set terminal value
-            let token = &token_vector[self.current_index + 1];
             if self.parser_type == "json" {
-                self.put_into(dict_boxed_ref, key, token);
+                self.put_into(dict_boxed_ref, key, value_token)?;
             } else {
-                self.put_plus_into(dict_boxed_ref, key, token);
+                self.put_plus_into(dict_boxed_ref, key, value_token)?;
             }
             // shift current index to point at next iter key token
             self.current_index += 2;
         // Case 2, next token is dict opening.
-        } else if token_vector[self.current_index + 1].get_type()
-                == JsonTokenType::DictOpening
-        {
+        } else if value_token.get_type() == JsonTokenType::DictOpening {
             // build a dict recursively
-            let subdict_boxed = self.make_dict(self.current_index + 1, token_vector);
+            let subdict_boxed = self.make_dict(self.current_index + 1, token_vector)?;
             // set item: insert a dict into list
             dict_boxed_ref.insert_box(key, subdict_boxed);
         // Case 3, next token is list opening.
-        } else if token_vector[self.current_index + 1].get_type()
-                == JsonTokenType::ListOpening
-        {
+        } else if value_token.get_type() == JsonTokenType::ListOpening {
             // build a list
-            let sublist_boxed = self.make_list(self.current_index + 1, token_vector);
+            let sublist_boxed = self.make_list(self.current_index + 1, token_vector)?;
             // set item: insert a list into list
             dict_boxed_ref.insert_box(key, sublist_boxed);
-        } else { /* TODO */ }
+        } else {
+            return Err(self.unexpected_token(value_token));
+        }
+        Ok(())
     }
 
     fn at_key(&mut self, dict_boxed_ref: &mut Box<Dict>,
-              token_vector: &Vec<JsonToken>)
+              token_vector: &[JsonToken])
+    -> Result<(), ParserError>
     {
-        let key = &token_vector[self.current_index].literal.clone();
-        self.fill_dict(dict_boxed_ref, &key, token_vector);
+        let key = token_vector[self.current_index].literal.clone();
+        self.fill_dict(dict_boxed_ref, &key, token_vector)
     }
 
     fn at_iter_key(&mut self, list_boxed_ref: &mut Box<List>,
-                   token_vector: & Vec<JsonToken>)
+                   token_vector: &[JsonToken])
+    -> Result<(), ParserError>
     {
-        let iter_key = token_vector[self.current_index].literal.parse::<usize>().unwrap();
-        self.fill_list(list_boxed_ref, iter_key, token_vector);
+        let token = &token_vector[self.current_index];
+        match token.literal.parse::<usize>() {
+            Ok(iter_key) => self.fill_list(list_boxed_ref, iter_key, token_vector),
+            Err(_) => {
+                let msg = format!("Iter key '{}' isn't a valid index.", token.literal);
+                Err(self.token_error(&msg, ErrorKind::SyntaxError, token))
+            }
+        }
     }
 }
 
 /// Public methods
 impl JsonLoader {
-    pub fn load_from_parser(&mut self, parser: &JsonParser) {
+    pub fn load_from_parser(&mut self, parser: &JsonParser)
+    -> Result<(), ParserError>
+    {
         if self.parser_type != parser.parser_type {
-            panic!("Parser type doesn't match!");
+            let msg = format!("Parser type {} doesn't match loader type {}.",
+                              parser.parser_type, self.parser_type);
+            return Err(ParserError::new(&msg, ErrorKind::IncompatibleType));
         }
         let token_vector = parser.get_token_vector();
+        self.current_index = 0;
         self.root_container_type = parser.get_root_container_type();
         if self.root_container_type == JsonContainerType::Dict { // root container is a dict
-            let root_object_dict = self.make_root_dict(&token_vector);
+            let root_object_dict = self.make_root_dict(token_vector)?;
             self.global_dict.insert_box("ROOT_KEY_", root_object_dict);
         } else if self.root_container_type == JsonContainerType::List { // root container is a list
-            let root_object_list = self.make_root_list(&parser.get_token_vector()) ;
+            let root_object_list = self.make_root_list(token_vector)?;
             self.global_dict.insert_box("ROOT_KEY_", root_object_list);
         } else {
-            panic!("Root object type is undetermined.");
+            return Err(ParserError::new("Root object type is undetermined.",
+                                        ErrorKind::SyntaxError));
+        }
+        Ok(())
+    }
+
+    /// Parses a string with a parser of the loader's type and loads it.
+    pub fn load_from_str(&mut self, src_string: &str) -> Result<(), ParserError> {
+        let mut parser = self.make_parser();
+        parser.parse_string(src_string)?;
+        self.load_from_parser(&parser)
+    }
+
+    pub fn load_from_file(&mut self, filename: &str) -> Result<(), AbelError> {
+        let mut parser = self.make_parser();
+        parser.parse_file(filename)?;
+        Ok(self.load_from_parser(&parser)?)
+    }
+
+    /// Parses text from any reader and loads it.
+    pub fn load_from_reader<R: Read>(&mut self, reader: R)
+    -> Result<(), AbelError>
+    {
+        let mut parser = self.make_parser();
+        parser.parse_reader(reader)?;
+        Ok(self.load_from_parser(&parser)?)
+    }
+
+    pub fn get_global_dict(&self) -> &Dict {
+        &self.global_dict
+    }
+
+    /// Takes the whole global dictionary out of the loader.
+    pub fn into_global_dict(self) -> Dict {
+        self.global_dict
+    }
+
+    /// Takes the loaded document out of the loader, without the `ROOT_KEY_`
+    /// wrapper. A document of a single dict, list or scalar gives that value,
+    /// key-value pairs without enclosing braces give a dict, and several
+    /// values in a row give a list of them.
+    pub fn take_root(&mut self) -> Result<JsonRoot, ParserError> {
+        if let Ok(dict) = self.global_dict.get_mut_ref::<Dict>("ROOT_KEY_") {
+            let root = std::mem::take(dict);
+            self.global_dict.remove("ROOT_KEY_").ok();
+            return Ok(JsonRoot::Dict(root));
+        }
+        let mut root = match self.global_dict.get_mut_ref::<List>("ROOT_KEY_") {
+            Ok(list) => std::mem::take(list),
+            Err(_) => {
+                return Err(ParserError::new("No document has been loaded.",
+                                            ErrorKind::KeyNotFound));
+            }
+        };
+        self.global_dict.remove("ROOT_KEY_").ok();
+        // A single document is wrapped by the root list.
+        if root.len() == 1 {
+            if let Ok(dict) = root.get_mut_ref::<Dict>(0) {
+                return Ok(JsonRoot::Dict(std::mem::take(dict)));
+            }
+            if let Ok(list) = root.get_mut_ref::<List>(0) {
+                return Ok(JsonRoot::List(std::mem::take(list)));
+            }
+            if let Ok(scalar) = root.remove(0) {
+                return Ok(JsonRoot::Scalar(scalar));
+            }
+        }
+        Ok(JsonRoot::List(root))
+    }
+
+    /// Loads a string and returns its root, see `take_root`.
+    pub fn load_root_from_str(&mut self, src_string: &str)
+    -> Result<JsonRoot, ParserError>
+    {
+        self.load_from_str(src_string)?;
+        self.take_root()
+    }
+
+    /// Loads a string whose root must be a dictionary.
+    pub fn load_dict_from_str(&mut self, src_string: &str)
+    -> Result<Dict, ParserError>
+    {
+        match self.load_root_from_str(src_string)? {
+            JsonRoot::Dict(dict) => Ok(dict),
+            _ => Err(ParserError::new("The document root is not a dictionary.",
+                                      ErrorKind::MismatchedType)),
         }
     }
 
-    pub fn load_from_file(&mut self, filename: &str) {
+    /// Loads a file and returns its root, see `take_root`.
+    pub fn load_root_from_file(&mut self, filename: &str)
+    -> Result<JsonRoot, AbelError>
+    {
+        self.load_from_file(filename)?;
+        Ok(self.take_root()?)
+    }
+
+    fn make_parser(&self) -> JsonParser {
         if self.parser_type == "json" {
-            let mut parser = JsonParser::new();
-            parser.parse_file(filename).unwrap();
-            self.load_from_parser(&parser);    
+            JsonParser::new()
         } else { // JOSN+ need plus parser
-            let mut parser = JsonParser::new_plus();
-            parser.parse_file(filename).unwrap();
-            self.load_from_parser(&parser);    
+            JsonParser::new_plus()
         }
     }
+}
 
-    pub fn get_global_dict(&self) -> &Dict {
-        &self.global_dict
+impl Default for JsonLoader {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
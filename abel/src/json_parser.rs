@@ -1,14 +1,15 @@
 use std::fs::File;
-use std::io::{self, BufRead};
+use std::io::{self, BufRead, Read};
 use std::path::Path;
 
-use crate::error::{ErrorKind, Error, ParserError};
+use crate::error::{AbelError, ErrorKind, Error, ParserError};
 use crate::typefy::NamedType;
 use crate::symbol;
 use crate::util;
 use crate::delimiter;
 use crate::converter;
 use crate::parser::Parser;
+use crate::span::{Position, Span};
 use crate::json_token::{JsonTokenType, JsonToken, JsonContainerType,
     JsonTerminalType, LiteralScheme, tokenize, tokenize_key,
     tokenize_iter_key, tokenize_terminal, get_token_type_by_symbol,
@@ -34,9 +35,9 @@ impl JsonParser {
                 Ok(())
             }
         } else {
-            let msg = format!("Unknown type for root container. \
-                Root container type can be either List or Dict.");
-            Err(ParserError::new(&msg, ErrorKind::SyntaxError))
+            let msg = "Unknown type for root container. \
+                Root container type can be either List or Dict.";
+            Err(ParserError::new(msg, ErrorKind::SyntaxError))
         }
     }
 
@@ -60,6 +61,8 @@ impl JsonParser {
                                   cannot be recognised.", literal);
                 let mut error = ParserError::new(&msg, ErrorKind::SyntaxError);
                 error.set_line(self.current_line);
+                error.set_hint("Unquoted values are null, true, false or numbers. \
+                                Enclose strings in double quotation marks.");
                 Err(error)
             }
         } else if scheme == LiteralScheme::None {
@@ -98,6 +101,9 @@ impl JsonParser {
                                   cannot be recognised for JSON+.", literal);
                 let mut error = ParserError::new(&msg, ErrorKind::SyntaxError);
                 error.set_line(self.current_line);
+                error.set_hint("Unquoted values are null, true, false, numbers, complex \
+                                numbers, binaries or bitstrings. Enclose strings in \
+                                double quotation marks.");
                 Err(error)
             }
         } else if scheme == LiteralScheme::None {
@@ -113,14 +119,24 @@ impl JsonParser {
     }
 }
 
+impl Default for JsonParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl JsonParser {
     pub fn new() -> Self { // default parser is JSON type
         JsonParser {
             token_vector: Vec::<JsonToken>::new(),
             current_line: 0,
             current_column: 0,
+            current_offset: 0,
+            input_offset: 0,
             //latest_symbol: String::new(),
             current_literal: String::new(),
+            literal_start: None,
+            literal_end: Position::default(),
             current_level: 0,
             deepest_level: 0,
             current_container_type: vec![JsonContainerType::None],
@@ -129,7 +145,17 @@ impl JsonParser {
             keys_per_level: vec![vec![]],
             latest_syntactic_operator: String::new(),
             is_escaping: false,
+            escape_sequence: String::new(),
+            pending_surrogate: None,
+            is_comment_open: false,
+            feed_buffer: Vec::new(),
+            is_line_open: false,
+            is_carriage_return_pending: false,
+            recovery_mode: false,
+            errors: Vec::new(),
+            skip_depth: None,
             is_delimited_string_open: false,
+            is_value_closed: false,
             current_literal_scheme: LiteralScheme::None,
             bracket_match: delimiter::DelimiterMatch::new(),
             parser_type: String::from("json")
@@ -141,8 +167,12 @@ impl JsonParser {
             token_vector: Vec::<JsonToken>::new(),
             current_line: 0,
             current_column: 0,
+            current_offset: 0,
+            input_offset: 0,
             //latest_symbol: String::new(),
             current_literal: String::new(),
+            literal_start: None,
+            literal_end: Position::default(),
             current_level: 0,
             deepest_level: 0,
             current_container_type: vec![JsonContainerType::None],
@@ -151,7 +181,17 @@ impl JsonParser {
             keys_per_level: vec![vec![]],
             latest_syntactic_operator: String::new(),
             is_escaping: false,
+            escape_sequence: String::new(),
+            pending_surrogate: None,
+            is_comment_open: false,
+            feed_buffer: Vec::new(),
+            is_line_open: false,
+            is_carriage_return_pending: false,
+            recovery_mode: false,
+            errors: Vec::new(),
+            skip_depth: None,
             is_delimited_string_open: false,
+            is_value_closed: false,
             current_literal_scheme: LiteralScheme::None,
             bracket_match: delimiter::DelimiterMatch::new(),
             parser_type: String::from("json_plus")
@@ -165,7 +205,7 @@ impl JsonParser {
         let mut err_msg = String::new();
         if self.keys_per_level.len() <= self.current_level as usize {
             self.keys_per_level.push(vec![token]);
-            return Ok(());
+            Ok(())
         } else {
             for token_exist in &self.keys_per_level[self.current_level as usize] {
                 if token_exist.parent_key == token.parent_key
@@ -177,78 +217,85 @@ impl JsonParser {
             }
             if !is_duplicate {
                 self.keys_per_level[self.current_level as usize].push(token);
-                return Ok(());
+                Ok(())
             } else {
                 let mut error = ParserError::new(&err_msg, ErrorKind::DuplicateKey);
                 error.set_line(self.current_line);
-                return Err(error);
+                error.set_hint("Keys must be unique within a dictionary.");
+                Err(error)
             }       
         }
     }
 
     fn make_and_push_key_token(&mut self) -> Result<(), ParserError> {
         let mut msg = String::new();
+        let mut hint = "";
         if self.get_current_container_type() != JsonContainerType::Dict {
             // current container must be dictionary
             msg = "Key is meaningful only in dictionary.".to_string();
         }
         // If previous token is key, emits error.
-        if self.token_vector.len() > 0
+        if !self.token_vector.is_empty()
                 && self.token_vector.last().unwrap().get_type() == JsonTokenType::Key
         {
             msg = "Key cannot follow a key immediately.".to_string();
+            hint = "Did you forget a comma?";
         }
         // Caution: JSON keys must be in delimited scheme
         if self.current_literal_scheme == LiteralScheme::Delimited {
-            let key_token = tokenize_key(
+            let mut key_token = tokenize_key(
                     &self.current_literal,
                     &self.parent_key[self.current_level as usize],
                     self.current_level,
                     self.current_line,
                     self.get_current_container_type(),
                     self.current_literal_scheme);
+            key_token.span = self.get_literal_span();
             self.push(key_token.clone());
-            if let Err(error) = self.report_duplicate_key(key_token) {
-                msg = error.full_message();
-            }
+            self.report_duplicate_key(key_token)?;
         } else {
-            msg = format!("Key '{}' isn't quoted. Keys in JSON must be enclosed
-                    in double-quotation marks.", self.current_literal);
+            msg = format!("Key '{}' isn't quoted. Keys in JSON must be enclosed \
+                          in double-quotation marks.", self.current_literal);
+            hint = "Enclose the key in double quotation marks.";
         }
 
-        if msg != "" { // if error, throw.
+        if !msg.is_empty() { // if error, throw.
             let mut error = ParserError::new(&msg, ErrorKind::SyntaxError);
             error.set_line(self.current_line);
-            return Err(error);
+            if !hint.is_empty() {
+                error.set_hint(hint);
+            }
+            Err(error)
         } else {
             // Set parent key for the next level.
             if self.parent_key.len() >= (self.current_level + 2) as usize {
                 self.parent_key[(self.current_level + 1) as usize]
                         = self.current_literal.clone();
-                return Ok(());
+                Ok(())
             } else {
                 // This key becomes the parent key for the next level.
                 self.parent_key.push(self.current_literal.clone());
-                return Ok(());
+                Ok(())
             }
         }
     }
 
-    fn per_iterable_container(&mut self) {
+    fn per_iterable_container(&mut self, span: Span) {
         if self.is_current_container_iterable() {
-            if self.current_iter_index.len() == 0 {
+            if self.current_iter_index.is_empty() {
                 self.current_iter_index.push(0);
             }
             let name_string: String
                     = self.current_iter_index[self.current_level as usize]
                           .to_string().clone();
-            let iter_key_token = tokenize_iter_key(
+            let mut iter_key_token = tokenize_iter_key(
                 &name_string,
                 &self.parent_key[self.current_level as usize],
                 self.current_level,
                 self.current_line,
                 self.get_current_container_type(),
                 self.current_iter_index[self.current_level as usize]);
+            iter_key_token.span = span;
             self.push(iter_key_token);
             // update parent key by consuming `name_string`
             if self.parent_key.len() >= (self.current_level as usize) + 2 {
@@ -276,25 +323,44 @@ impl JsonParser {
         }
     }
 
+    // Key or list index of the value at the current level, set by the key
+    // token or the iter key token before it.
+    fn get_value_parent_key(&self) -> Result<&String, ParserError> {
+        match self.parent_key.get((self.current_level as usize) + 1) {
+            Some(parent_key) => Ok(parent_key),
+            None => {
+                let msg = "Value has neither a key nor a list index.";
+                let mut error = ParserError::new(msg, ErrorKind::SyntaxError);
+                error.set_line(self.current_line);
+                Err(error)
+            }
+        }
+    }
+
     fn make_and_push_terminal_token(&mut self) -> Result<(), ParserError> {
-        self.per_iterable_container();
-        let terminal_type: JsonTerminalType;
+        let span = self.get_literal_span();
+        self.per_iterable_container(span);
         // available terminal types are dependent on parser type
-        if self.parser_type == "json" {
-            terminal_type = self.get_terminal_type(
-                    &self.current_literal, self.current_literal_scheme)?;
+        let terminal_type = if self.parser_type == "json" {
+            self.get_terminal_type(&self.current_literal, self.current_literal_scheme)
         } else {
-            terminal_type = self.get_terminal_plus_type(
-                &self.current_literal,  self.current_literal_scheme)?;
-        }
-        let terminal_token = tokenize_terminal(
+            self.get_terminal_plus_type(&self.current_literal, self.current_literal_scheme)
+        };
+        // Error points at the literal instead of the character ending it.
+        let terminal_type = terminal_type.map_err(|mut error| {
+            error.set_span(span);
+            error
+        })?;
+        let parent_key = self.get_value_parent_key()?;
+        let mut terminal_token = tokenize_terminal(
             &self.current_literal,
-            &self.parent_key[(self.current_level as usize) + 1], // Note the level for PK
+            parent_key,
             self.current_level,
             self.current_line,
             self.get_current_container_type(),
             terminal_type,
             self.current_literal_scheme);
+        terminal_token.span = span;
         self.per_pushing_terminal_token()?;
         self.push(terminal_token);
         Ok(())
@@ -305,7 +371,8 @@ impl JsonParser {
     -> Result<(), ParserError>
     {
         let mut msg = String::new();
-        if self.token_vector.len() > 0 {
+        let mut hint = "";
+        if !self.token_vector.is_empty() {
             let veclen = self.token_vector.len();
             // Case: curent container is Dict, but last token is NOT key.
             if self.get_current_container_type() == JsonContainerType::Dict
@@ -313,12 +380,14 @@ impl JsonParser {
             {
                 // curent container is Dict, but last token is NOT key.
                 msg = "In dictionary, an object must be preceeded by a key.".to_string();
+                hint = "Did you forget a key, or a comma before it?";
             } else if self.is_current_container_iterable()
                 && self.token_vector[veclen - 1].get_type() != JsonTokenType::IterKey
             {
             // Case: current container is iterable, but last token is NOT iter-key.
                 msg = "In a iterable container, any object must be preceeded \
                         by an iter key.".to_string();
+                hint = "Did you forget a comma?";
             } else {
                 // JSON allows either Dict or List container.
                 if opening_symbol == symbol::L_BRACE {
@@ -327,13 +396,14 @@ impl JsonParser {
                 } else if opening_symbol == symbol::L_BRACKET {
                     self.token_vector[veclen - 1]
                         .referenced_type = "List".to_string();
-                } else {}
+                }
             }
         }
         // Return Ok or Err
-        if msg != "" {
+        if !msg.is_empty() {
             let mut error = ParserError::new(&msg, ErrorKind::SyntaxError);
             error.set_line(self.current_line);
+            error.set_hint(hint);
             Err(error)
         } else {
             Ok(())
@@ -343,15 +413,18 @@ impl JsonParser {
     fn make_and_push_container_opening_token(&mut self, opening_sym: char)
     -> Result<(), ParserError>
     {
-        self.per_iterable_container();
+        let span = Span::of_char(self.get_current_position(), opening_sym);
+        self.per_iterable_container(span);
         self.per_pushing_container_opening_token(opening_sym)?;
-        let opening_token = tokenize(
+        let parent_key = self.get_value_parent_key()?;
+        let mut opening_token = tokenize(
             &String::from(opening_sym),
             get_token_type_by_symbol(opening_sym).unwrap(),
-            &self.parent_key[(self.current_level as usize) + 1], // Note the level
+            parent_key,
             self.current_level,
             self.current_line,
             self.get_current_container_type() );
+        opening_token.span = span;
         self.push(opening_token);
         Ok(())
     }
@@ -361,7 +434,7 @@ impl JsonParser {
         if self.get_current_container_type() == JsonContainerType::List{
             self.current_iter_index[self.current_level as usize] = 0;
         }
-        let closing_token = tokenize(
+        let mut closing_token = tokenize(
             &String::from(closing_sym),
             get_token_type_by_symbol(closing_sym).unwrap(),
             &self.parent_key[self.current_level as usize],
@@ -370,6 +443,7 @@ impl JsonParser {
             // Caution. Must use the container of the outer (higher) level, since
             // level switching is performed after pushing the closing token.
             self.current_container_type[(self.current_level as usize) - 1]);
+        closing_token.span = Span::of_char(self.get_current_position(), closing_sym);
         self.push(closing_token);
     }
 
@@ -378,34 +452,143 @@ impl JsonParser {
     fn per_space(&mut self) -> Result<(),()> {
         match self.is_delimited_string_open {
             true => {
-                self.current_literal.push_str(" ");
+                self.current_literal.push(' ');
                 Ok(())
             },
-            _ => Ok(())
+            _ => {
+                self.close_liberal_literal();
+                Ok(())
+            }
         }
     }
+
+    // Whitespace ends an unquoted literal.
+    fn close_liberal_literal(&mut self) {
+        if !self.is_delimited_string_open && !self.current_literal.is_empty()
+                && self.current_literal_scheme == LiteralScheme::Liberal {
+            self.is_value_closed = true;
+        }
+    }
+
+    // A new literal, string or container must not follow a complete value.
+    fn check_comma_before_value(&self) -> Result<(), ParserError> {
+        if self.is_value_closed {
+            let mut error = ParserError::new("Comma is missing between two values.",
+                                             ErrorKind::SyntaxError);
+            error.set_line(self.current_line);
+            error.set_hint("Add a comma after the previous value.");
+            return Err(error);
+        }
+        Ok(())
+    }
     
     fn per_back_slash(&mut self) {
         match self.is_delimited_string_open {
-            true => {
-                match self.is_escaping {
-                    true => {
-                        self.current_literal.push_str("\\");
-                        self.is_escaping = false;
-                    },
-                    false => self.is_escaping = true,
+            true => self.is_escaping = true,
+            false => {
+                self.start_literal();
+                self.current_literal.push('\\'); // append
+            },
+        }
+    }
+
+    // Marks the current character as the start of a literal.
+    fn start_literal(&mut self) {
+        if self.literal_start.is_none() {
+            self.literal_start = Some(self.get_current_position());
+        }
+    }
+
+    fn escape_error(&self, msg: &str) -> ParserError {
+        let mut error = ParserError::new(msg, ErrorKind::SyntaxError);
+        error.set_line(self.current_line);
+        error
+    }
+
+    /// Decodes the character after a back slash inside a delimited string,
+    /// following RFC 8259. A `\uXXXX` escape is collected over several calls.
+    fn per_escaped_char(&mut self, current_char: char) -> Result<(), ParserError> {
+        if self.escape_sequence.is_empty() {
+            let decoded = match current_char {
+                '"' => '"',
+                '\\' => '\\',
+                '/' => '/',
+                'b' => '\u{08}',
+                'f' => '\u{0C}',
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                'u' => {
+                    self.escape_sequence.push(current_char);
+                    return Ok(());
+                },
+                _ => {
+                    let msg = format!("Invalid escape sequence '\\{}'.", current_char);
+                    let mut error = self.escape_error(&msg);
+                    error.set_hint("Valid escapes are \\\" \\\\ \\/ \\b \\f \\n \\r \\t \
+                                    and \\uXXXX.");
+                    return Err(error);
                 }
+            };
+            self.is_escaping = false;
+            self.check_no_pending_surrogate()?;
+            self.current_literal.push(decoded);
+            return Ok(());
+        }
+        if !current_char.is_ascii_hexdigit() {
+            let msg = format!("Invalid unicode escape sequence '\\{}{}'.",
+                              self.escape_sequence, current_char);
+            return Err(self.escape_error(&msg));
+        }
+        self.escape_sequence.push(current_char);
+        if self.escape_sequence.len() < 5 {
+            return Ok(());
+        }
+        let code = u32::from_str_radix(&self.escape_sequence[1..], 16).unwrap();
+        self.escape_sequence.clear();
+        self.is_escaping = false;
+        if let Some(high) = self.pending_surrogate.take() {
+            if !(0xDC00..=0xDFFF).contains(&code) {
+                let msg = format!("High surrogate \\u{:04X} isn't followed \
+                                  by a low surrogate.", high);
+                return Err(self.escape_error(&msg));
+            }
+            let combined = 0x10000 + ((high - 0xD800) << 10) + (code - 0xDC00);
+            self.current_literal.push(char::from_u32(combined).unwrap());
+        } else if (0xD800..=0xDBFF).contains(&code) {
+            self.pending_surrogate = Some(code);
+        } else if (0xDC00..=0xDFFF).contains(&code) {
+            let msg = format!("Low surrogate \\u{:04X} without a high surrogate.",
+                              code);
+            return Err(self.escape_error(&msg));
+        } else {
+            self.current_literal.push(char::from_u32(code).unwrap());
+        }
+        Ok(())
+    }
+
+    /// A high surrogate must be followed immediately by `\u` and its low half.
+    fn check_no_pending_surrogate(&mut self) -> Result<(), ParserError> {
+        match self.pending_surrogate.take() {
+            Some(high) => {
+                let msg = format!("High surrogate \\u{:04X} isn't followed \
+                                  by a low surrogate.", high);
+                Err(self.escape_error(&msg))
             },
-            false => self.current_literal.push_str("\\"), // append
+            None => Ok(()),
         }
     }
 
-    fn per_double_quotation(&mut self) {
+    fn per_double_quotation(&mut self) -> Result<(), ParserError> {
+        if !self.is_delimited_string_open {
+            self.check_comma_before_value()?;
+        }
     // respect two-character escaping sequence
-        if self.current_literal == "" {
+        if self.current_literal.is_empty() {
         // If current string literal is empty, double quote
         // is treated as a string-opening operator.
             if !self.is_delimited_string_open {
+                self.start_literal();
                 self.is_delimited_string_open = true;
                 self.current_literal_scheme = LiteralScheme::Delimited;
             } else {
@@ -418,21 +601,14 @@ impl JsonParser {
         // current literal is non-empty
             if self.is_delimited_string_open {
             // a delimited string has opened...
-                if self.is_escaping { // previous char is escaping
-                    // then double quote is a character of the string
-                    // add double quote to the current literal.
-                    self.current_literal.push_str(&symbol::DOUBLE_QUOTE.to_string());
-                    // swith off escaping flag.
-                    self.is_escaping = false;
-                } else {
-                    // then double quote is a string-closing operator
-                    self.is_delimited_string_open = false;
-                    // set double quote as the latest syntactic operator.
-                    self.latest_syntactic_operator = symbol::DOUBLE_QUOTE.to_string();
-                }
+                // Escaped double quotes are decoded by `per_escaped_char`,
+                // so this one is a string-closing operator.
+                self.is_delimited_string_open = false;
+                // set double quote as the latest syntactic operator.
+                self.latest_syntactic_operator = symbol::DOUBLE_QUOTE.to_string();
             } else {
                 if self.current_literal_scheme == LiteralScheme::Liberal {
-                    self.current_literal.push_str(&symbol::DOUBLE_QUOTE.to_string()); // append
+                    self.current_literal.push(symbol::DOUBLE_QUOTE); // append
                 } else {
                     // If not LIBERAL scheme, the previous quoted string has been
                     // closed; this quotation mark starts a new delimited string.
@@ -442,12 +618,13 @@ impl JsonParser {
                 }
             }
         }
+        Ok(())
     }
 
     fn per_colon(&mut self) -> Result<(), ParserError> {
         let mut msg = String::new();
         if self.is_delimited_string_open {
-            self.current_literal.push_str(&symbol::COLON.to_string());
+            self.current_literal.push(symbol::COLON);
             Ok(())
         } else {
             // Pre-tokenization syntax checks
@@ -460,21 +637,21 @@ impl JsonParser {
             if self.is_current_container_iterable() {
                 msg = "Colon operator is meaningless.".to_string();
             }
-            if self.current_literal.len() == 0 {
-                msg = "Colon operator must appear after
+            if self.current_literal.is_empty() {
+                msg = "Colon operator must appear after \
                       a non-empty key token.".to_string();
             }
             if self.latest_syntactic_operator == symbol::COLON.to_string() {
-                msg = "Colon operator cannot appear immediately
+                msg = "Colon operator cannot appear immediately \
                       after a colon operator.".to_string();
             }
 
-            if msg != "" {
+            if !msg.is_empty() {
                 let mut error = ParserError::new(&msg, ErrorKind::SyntaxError);
                 error.set_line(self.current_line);
                 Err(error)
             } else {
-                if self.current_literal.len() > 0 {
+                if !self.current_literal.is_empty() {
                     if self.current_container_type[0] == JsonContainerType::None {
                         self.set_root_container_type(JsonContainerType::Dict)?; 
                     }
@@ -490,8 +667,8 @@ impl JsonParser {
     fn per_comma(&mut self) -> Result<(), ParserError> {
         let mut msg = String::new();
         if self.is_delimited_string_open {
-            self.current_literal.push_str(&symbol::COMMA.to_string()); // append
-            return Ok(());
+            self.current_literal.push(symbol::COMMA); // append
+            Ok(())
         } else {
             // Syntax checks
             if self.is_first_noncomment_character() {
@@ -500,22 +677,22 @@ impl JsonParser {
                     msg = error.full_message();
                 }
             }
-            if self.current_literal.len() == 0 {
-                if !(self.latest_syntactic_operator == symbol::DOUBLE_QUOTE.to_string()
+            if self.current_literal.is_empty()
+                    && !(self.latest_syntactic_operator == symbol::DOUBLE_QUOTE.to_string()
                         || self.latest_syntactic_operator == symbol::R_BRACE.to_string()
                         || self.latest_syntactic_operator == symbol::R_BRACKET.to_string())
-                {
-                    msg = "Comma can only appear after a terminal, \
-                            a string, or a container closing operator.".to_string();
-                }
+            {
+                msg = "Comma can only appear after a terminal, \
+                      a string, or a container closing operator.".to_string();
             }
-            if msg != "" {
+            if !msg.is_empty() {
                 let mut error = ParserError::new(&msg, ErrorKind::SyntaxError);
                 error.set_line(self.current_line);
-                return Err(error);
+                error.set_hint("Remove the extra comma.");
+                Err(error)
             } else {
-                if self.current_literal.len() > 0
-                        || (self.current_literal.len() == 0
+                if !self.current_literal.is_empty()
+                        || (self.current_literal.is_empty()
                             && self.latest_syntactic_operator == "\"") {
                     if self.current_container_type[0] == JsonContainerType::None {
                         self.set_root_container_type(JsonContainerType::List)?;
@@ -530,13 +707,14 @@ impl JsonParser {
                         || last_token_type == JsonTokenType::DictClosing
                         || last_token_type == JsonTokenType::ListClosing) {
                     let mut error = ParserError::new(
-                            &"Comma is meaningless.".to_string(),
+                            "Comma is meaningless.",
                             ErrorKind::SyntaxError);
                     error.set_line(self.current_line);
-                    return Err(error);
+                    error.set_hint("Remove the extra comma.");
+                    Err(error)
                 } else {
                     self.latest_syntactic_operator = symbol::COMMA.to_string();
-                    return Ok(());
+                    Ok(())
                 }
             }
         }
@@ -547,8 +725,8 @@ impl JsonParser {
     {
         let container_type = get_container_type_by_symbol(opening_sym).unwrap();
         if self.is_delimited_string_open {
-            self.current_literal.push_str(&opening_sym.to_string()); // append
-            return Ok(());
+            self.current_literal.push(opening_sym); // append
+            Ok(())
         } else {
             if self.is_first_noncomment_character() {
                 if self.current_container_type[0] == JsonContainerType::None {
@@ -570,13 +748,22 @@ impl JsonParser {
             }
             self.make_and_push_container_opening_token(opening_sym)?;
             self.enter_deeper_level();
-            self.current_iter_index.push(0);
+            // Index at the new level may be left over from an earlier container.
+            if self.current_iter_index.len() > self.current_level as usize {
+                self.current_iter_index[self.current_level as usize] = 0;
+            } else {
+                self.current_iter_index.push(0);
+            }
+            // Likewise, keys seen at this level belong to an earlier container.
+            if let Some(keys) = self.keys_per_level.get_mut(self.current_level as usize) {
+                keys.clear();
+            }
             // update deepest level
             if self.current_level > self.deepest_level {
                 self.deepest_level = self.current_level;
             }
             self.latest_syntactic_operator = opening_sym.to_string();
-            return Ok(());
+            Ok(())
         }
     }
 
@@ -584,168 +771,493 @@ impl JsonParser {
     -> Result<(), ParserError>
     {
         if self.is_delimited_string_open { // if delimited, append it to literal
-            self.current_literal.push_str(&closing_sym.to_string());
-            return Ok(());
+            self.current_literal.push(closing_sym);
+            Ok(())
         } else { // if liberal, it is closing token
             if self.is_first_noncomment_character() {
                 self.illegal_first_noncomment_character(&closing_sym.to_string())?;
             }
+            if self.current_level == 0 {
+                let msg = format!("Closing bracket '{}' has no opening bracket.",
+                                  closing_sym);
+                let mut error = ParserError::new(&msg, ErrorKind::UnpairedBrackets);
+                error.set_line(self.current_line);
+                error.set_hint("Remove the extra closing bracket.");
+                return Err(error);
+            }
             self.bracket_match.match_symbol(
                     closing_sym, self.current_line, self.current_column);
-            // Current literal is not empty
-            if self.current_literal.len() > 0 {
+            // Current literal is not empty, or is an empty quoted string
+            if !self.current_literal.is_empty()
+                    || self.latest_syntactic_operator == symbol::DOUBLE_QUOTE.to_string() {
                 self.make_and_push_terminal_token()?;
                 self.reset_current_literal();
             }
             self.make_and_push_container_closing_token(closing_sym);
             // TODO
             if util::is_iterable_container(
-                    &self.current_container_type[(self.current_level as usize) - 1].type_name()) {
+                    self.current_container_type[(self.current_level as usize) - 1].type_name()) {
                 self.current_iter_index[self.current_level as usize] = 0;
                 self.current_iter_index[(self.current_level as usize) - 1] += 1;
             }
             self.enter_higher_level();
             self.latest_syntactic_operator = closing_sym.to_string();
-            return Ok(());
+            Ok(())
         }
     }
     
     fn per_other_symbol(&mut self, current_char: char) -> Result<(), ParserError> {
+        if !self.is_delimited_string_open {
+            self.check_comma_before_value()?;
+        }
         // Current literal is empty, new literal collection starts...
-        if self.current_literal.len() == 0 {
+        if self.current_literal.is_empty() {
             // If inside delimited string is on, this is just after double
             // quotation mark, simply append the character
             if self.is_delimited_string_open {
-                self.current_literal.push_str(&current_char.to_string());
+                self.current_literal.push(current_char);
             } else {
+                self.start_literal();
                 self.current_literal_scheme = LiteralScheme::Liberal;
-                self.current_literal.push_str(&current_char.to_string());
+                self.current_literal.push(current_char);
             }
-            return Ok(());
+            Ok(())
         } else { // Current literal is non-empty
-            if self.is_delimited_string_open == false
+            if !self.is_delimited_string_open
                 && self.current_literal_scheme == LiteralScheme::Delimited
             {
                 // A delimited string is before this character, for example
                 // "first"name, i.e. appending a liberal string to a delimited
-                // one is not allowed.
-                let mut error = ParserError::new(
-                    "Appending a liberal string to a delimited one is forbidden.",
-                    ErrorKind::SyntaxError);
+                // one is not allowed. In a dictionary with no key yet, the
+                // string is the key and its colon is missing.
+                let is_key = self.get_current_container_type() == JsonContainerType::Dict
+                    && self.token_vector.last()
+                           .is_none_or(|token| token.get_type() != JsonTokenType::Key);
+                let mut error = if is_key {
+                    let msg = format!("Colon is missing after key '{}'.", self.current_literal);
+                    let mut error = ParserError::new(&msg, ErrorKind::SyntaxError);
+                    error.set_hint("Add a colon between the key and its value.");
+                    error
+                } else {
+                    let mut error = ParserError::new(
+                        "Appending a liberal string to a delimited one is forbidden.",
+                        ErrorKind::SyntaxError);
+                    error.set_hint("Enclose the whole string in double quotation marks.");
+                    error
+                };
                 error.set_line(self.current_line);
-                return Err(error);
+                Err(error)
             } else {
-                self.current_literal.push_str(&current_char.to_string());
-                return Ok(());
+                self.current_literal.push(current_char);
+                Ok(())
             }
         }
     }
 
     // TODO
     fn per_end_of_file(&mut self) -> Result<(), ParserError> {
-        if self.current_literal != "" {
+        if self.is_escaping {
+            return Err(self.escape_error("Escape sequence is incomplete."));
+        }
+        self.check_no_pending_surrogate()?;
+        if !self.current_literal.is_empty()
+                || self.latest_syntactic_operator == symbol::DOUBLE_QUOTE.to_string() {
+            // A scalar document, e.g. `42` or `"text"`, is held by a root
+            // list, the same as a single container.
+            if self.current_container_type[0] == JsonContainerType::None {
+                self.set_root_container_type(JsonContainerType::List)?;
+            }
             self.make_and_push_terminal_token()?;
         }
         self.reset_current_literal();
-        return Ok(());
+        Ok(())
+    }
+
+    // Checks after the last line of input.
+    fn finish_parsing(&mut self) -> Result<(), ParserError> {
+        let end_span = self.end_of_input_span();
+        self.skip_depth = None;
+        if let Err(error) = self.per_end_of_file() {
+            let error = self.locate_error(error, end_span);
+            if !self.recovery_mode {
+                return Err(error);
+            }
+            self.errors.push(error);
+        }
+        if !self.bracket_match.are_all_matched() {
+            let mut error = ParserError::new("Unpaired brackets found.",
+                                             ErrorKind::UnpairedBrackets);
+            error.set_hint("Every opening bracket needs a matching closing one.");
+            error.set_span(end_span);
+            if !self.recovery_mode {
+                return Err(error);
+            }
+            self.errors.push(error);
+        }
+        // In recovery mode, the first of the collected errors is returned.
+        match self.errors.first() {
+            Some(error) => Err(error.clone()),
+            None => Ok(()),
+        }
+    }
+
+    // Zero-width span just after the last character of input.
+    fn end_of_input_span(&self) -> Span {
+        let end = Position::new(self.current_line, self.current_column,
+                                self.input_offset);
+        Span::new(end, end)
+    }
+
+    // Errors without a span point at the given source text.
+    fn locate_error(&self, mut error: ParserError, span: Span) -> ParserError {
+        if error.get_span().is_none() {
+            error.set_span(span);
+        }
+        error
+    }
+
+    fn parse_char(&mut self, current_char: char) -> Result<(), ParserError> {
+        if self.skip_depth.is_some() {
+            self.skip_char(current_char);
+            return Ok(());
+        }
+        let span = Span::of_char(self.get_current_position(), current_char);
+        if let Err(error) = self.dispatch_char(current_char) {
+            let error = self.locate_error(error, span);
+            if !self.recovery_mode {
+                return Err(error);
+            }
+            self.recover(error, current_char);
+            return Ok(());
+        }
+        // Quotation marks and characters of a literal extend its span,
+        // spaces and comments after it don't.
+        if self.literal_start.is_some() && !self.is_comment_open
+                && (current_char != symbol::SPACE || self.is_delimited_string_open) {
+            self.literal_end = span.end;
+        }
+        Ok(())
+    }
+
+    // Error recovery
+
+    /// Records an error and resynchronises at the next comma or closing
+    /// bracket of the current container.
+    fn recover(&mut self, error: ParserError, current_char: char) {
+        let is_unpaired = error.get_kind() == ErrorKind::UnpairedBrackets;
+        self.errors.push(error);
+        let is_in_string = self.is_delimited_string_open;
+        self.reset_current_literal();
+        self.current_literal_scheme = LiteralScheme::None;
+        // The failed value is dropped, so neither its quotes nor its key are
+        // left for the closing bracket or the next value.
+        self.latest_syntactic_operator.clear();
+        self.parent_key.truncate((self.current_level as usize) + 1);
+        self.is_delimited_string_open = false;
+        self.is_value_closed = false;
+        self.is_escaping = false;
+        self.escape_sequence.clear();
+        self.pending_surrogate = None;
+        if is_in_string {
+            // rest of the string is skipped first
+            self.is_delimited_string_open = true;
+            self.skip_depth = Some(0);
+        } else if current_char == symbol::COMMA {
+            self.resync_at_comma();
+        } else if util::is_closing_symbol(current_char) {
+            // an extra closing bracket is simply dropped
+            if !is_unpaired {
+                self.resync_at_closing(current_char);
+            }
+        } else {
+            self.skip_depth = Some(0);
+        }
+    }
+
+    // Skips a character until resynchronisation. Strings and nested
+    // containers are skipped as a whole.
+    fn skip_char(&mut self, current_char: char) {
+        if self.is_delimited_string_open {
+            if self.is_escaping {
+                self.is_escaping = false;
+            } else if current_char == symbol::BACK_SLASH {
+                self.is_escaping = true;
+            } else if current_char == symbol::DOUBLE_QUOTE {
+                self.is_delimited_string_open = false;
+            }
+            return;
+        }
+        let depth = self.skip_depth.unwrap_or(0);
+        if current_char == symbol::DOUBLE_QUOTE {
+            self.is_delimited_string_open = true;
+        } else if current_char == symbol::SHARP {
+            self.is_comment_open = true;
+        } else if util::is_opening_symbol(current_char) {
+            self.skip_depth = Some(depth + 1);
+        } else if util::is_closing_symbol(current_char) {
+            if depth > 0 {
+                self.skip_depth = Some(depth - 1);
+            } else {
+                self.skip_depth = None;
+                self.resync_at_closing(current_char);
+            }
+        } else if current_char == symbol::COMMA && depth == 0 {
+            self.skip_depth = None;
+            self.resync_at_comma();
+        }
+    }
+
+    // A key or an iter key whose value failed is removed, so that the
+    // partial token vector stays loadable.
+    fn drop_dangling_key(&mut self) {
+        if let Some(token) = self.token_vector.last() {
+            if token.get_type() == JsonTokenType::Key
+                    || token.get_type() == JsonTokenType::IterKey {
+                self.token_vector.pop();
+            }
+        }
+    }
+
+    fn resync_at_comma(&mut self) {
+        self.drop_dangling_key();
+        self.latest_syntactic_operator = symbol::COMMA.to_string();
+    }
+
+    fn resync_at_closing(&mut self, closing_sym: char) {
+        self.drop_dangling_key();
+        let span = Span::of_char(self.get_current_position(), closing_sym);
+        if let Err(error) = self.per_container_closing(closing_sym) {
+            let error = self.locate_error(error, span);
+            self.errors.push(error);
+        }
+    }
+
+    fn dispatch_char(&mut self, current_char: char) -> Result<(), ParserError> {
+        if self.is_escaping {
+            return self.per_escaped_char(current_char);
+        } else if current_char != symbol::BACK_SLASH {
+            self.check_no_pending_surrogate()?;
+        }
+        if current_char == symbol::BACK_SLASH {
+            self.per_back_slash();
+        } else if current_char == symbol::DOUBLE_QUOTE {
+            self.per_double_quotation()?;
+        } else if current_char == symbol::SPACE {
+            self.per_space().unwrap();
+        } else if current_char == symbol::SHARP { // at # symbol
+            if self.is_delimited_string_open {
+                self.current_literal.push(current_char);
+            } else {
+                // rest of the line is a comment
+                self.is_comment_open = true;
+            }
+        } else if current_char == symbol::COLON {
+            self.per_colon()?;
+            self.is_value_closed = false;
+        } else if current_char == symbol::COMMA {
+            self.per_comma()?;
+            self.is_value_closed = false;
+        } else if util::is_opening_symbol(current_char) {
+            if !self.is_delimited_string_open {
+                self.check_comma_before_value()?;
+            }
+            self.per_container_opening(current_char)?;
+        } else if util::is_closing_symbol(current_char) {
+            self.per_container_closing(current_char)?;
+            if !self.is_delimited_string_open {
+                self.is_value_closed = true;
+            }
+        } else { // none of the above
+            self.per_other_symbol(current_char)?;
+        }
+        Ok(())
     }
 
     fn parse_line(&mut self, line: &str) -> Result<(), ParserError> {
         // Rust way of loop through a string
-        for (index, current_char) in line.chars().enumerate() {
+        for (index, (byte_index, current_char)) in line.char_indices().enumerate() {
             self.current_column = index as i32;
-            if current_char == symbol::BACK_SLASH {
-                self.per_back_slash();
-                continue;
-            } else if current_char == symbol::DOUBLE_QUOTE {
-                self.per_double_quotation();
-                continue;
-            } else if current_char == symbol::SPACE {
-                self.per_space().unwrap();
-                continue;
-            } else if current_char == symbol::SHARP { // at # symbol
-                if self.is_delimited_string_open {
-                    self.current_literal.push_str(&current_char.to_string());
-                } else {
-                    break;
-                }
-                continue;
-            } else if current_char == symbol::COLON {
-                self.per_colon()?;
-                continue;
-            } else if current_char == symbol::COMMA {
-                self.per_comma()?;
-                continue;
-            } else if util::is_opening_symbol(current_char) {
-                self.per_container_opening(current_char)?;
-                continue;
-            } else if util::is_closing_symbol(current_char) {
-                self.per_container_closing(current_char)?;
-                continue;
-            } else { // none of the above
-                self.per_other_symbol(current_char)?;
-                continue;
+            self.current_offset = self.input_offset + byte_index;
+            self.parse_char(current_char)?;
+            if self.is_comment_open {
+                break;
             }
         }
+        self.is_comment_open = false;
+        self.close_liberal_literal();
+        // Position just after the line, for errors at the end of input
+        self.current_column = line.chars().count() as i32;
+        self.current_offset = self.input_offset + line.len();
+        Ok(())
+    }
+
+    /// Parses a line including its `\n` or `\r\n` ending, if any.
+    fn parse_raw_line(&mut self, raw_line: &str) -> Result<(), ParserError> {
+        self.current_line += 1;
+        self.current_column = 0;
+        let line = match raw_line.strip_suffix('\n') {
+            Some(line) => line.strip_suffix('\r').unwrap_or(line),
+            None => raw_line,
+        };
+        self.parse_line(line)?;
+        self.input_offset += raw_line.len();
         Ok(())
     }
+
+    /// Parses a character of chunked input, keeping track of lines the same
+    /// way as `str::lines`, i.e. `\r\n` ends a line as well as `\n`.
+    fn feed_char(&mut self, current_char: char) -> Result<(), ParserError> {
+        if self.is_carriage_return_pending {
+            self.is_carriage_return_pending = false;
+            if current_char != '\n' {
+                // the carriage return is the last byte read
+                self.feed_line_char('\r', self.input_offset - 1)?;
+            }
+        }
+        let offset = self.input_offset;
+        self.input_offset += current_char.len_utf8();
+        if current_char == '\r' {
+            self.is_carriage_return_pending = true;
+            Ok(())
+        } else if current_char == '\n' {
+            self.close_liberal_literal();
+            if !self.is_line_open { // an empty line
+                self.current_line += 1;
+            }
+            self.is_line_open = false;
+            self.is_comment_open = false;
+            Ok(())
+        } else {
+            self.feed_line_char(current_char, offset)
+        }
+    }
+
+    fn feed_line_char(&mut self, current_char: char, offset: usize)
+    -> Result<(), ParserError>
+    {
+        self.current_offset = offset;
+        if self.is_line_open {
+            self.current_column += 1;
+        } else {
+            self.is_line_open = true;
+            self.current_line += 1;
+            self.current_column = 0;
+        }
+        if self.is_comment_open {
+            return Ok(());
+        }
+        self.parse_char(current_char)
+    }
+
+    fn invalid_utf8_error(&self) -> ParserError {
+        let mut error = ParserError::new("Input isn't valid UTF-8.",
+                                         ErrorKind::InvalidInput);
+        error.set_span(self.end_of_input_span());
+        error
+    }
 }
 
 /// Public methods
 impl JsonParser {
     // Parse a string that consists of multiple lines.
     pub fn parse_string(&mut self, src_string: &str) -> Result<(), ParserError> {
-        for l in src_string.lines() {
-            self.current_line += 1;
-            self.current_column = 0;
-            let line_copy = String::from(l);
-            self.parse_line(&line_copy)?;
-        }
-        self.per_end_of_file()?;
-        if !self.bracket_match.are_all_matched() {
-            return Err(ParserError::new(
-                "Unpaired brackets found.",
-                ErrorKind::UnpairedBrackets));
-        } else {
-            return Ok(());
+        for raw_line in src_string.split_inclusive('\n') {
+            self.parse_raw_line(raw_line)?;
         }
+        self.finish_parsing()
     }
-    
-    // TODO
-    pub fn parse_file(&mut self, filename: &str) -> Result<(), ParserError> {
-        let path = Path::new(filename);
-        // Open the path in read-only mode, returns `io::Result<File>`
-        match File::open(&path) {
-            Err(why) => {
-                let error = ParserError::new(
-                    &format!("Failed to open {}: {}", path.display(), why),
-                    ErrorKind::FileNotFound);
-                Err(error)
-            },
-            Ok(file) => {
-                for res in io::BufReader::new(file).lines() {
-                    self.current_line += 1;
-                    self.current_column = 0;
-                    match res {
-                        Ok(line) => {
-                            let line_copy = String::from(line);
-                            self.parse_line(&line_copy)?;
-                        },
-                        Err(_) => continue
-                    }
-                }
-                self.per_end_of_file()?;
-                if !self.bracket_match.are_all_matched() {
-                    let error = ParserError::new("Unpaired brackets found.",
-                            ErrorKind::UnpairedBrackets);
-                    Err(error)
-                } else {
-                    Ok(())
-                }
+
+    pub fn parse_file(&mut self, filename: &str) -> Result<(), AbelError> {
+        let file = File::open(Path::new(filename))?;
+        self.parse_reader(file)
+    }
+
+    /// Parses a chunk of input. Chunks may be split anywhere, even inside a
+    /// string or a UTF-8 character; the tokens are the same as parsing the
+    /// whole text at once. Call `finish` after the last chunk. On an error,
+    /// the input after the failing character stays buffered.
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<(), ParserError> {
+        self.feed_buffer.extend_from_slice(chunk);
+        let buffer = std::mem::take(&mut self.feed_buffer);
+        // incomplete character at the end is kept for the next chunk
+        let (valid_len, is_valid) = match std::str::from_utf8(&buffer) {
+            Ok(text) => (text.len(), true),
+            Err(why) => (why.valid_up_to(), why.error_len().is_none()),
+        };
+        let text = std::str::from_utf8(&buffer[..valid_len]).unwrap();
+        let mut consumed = 0;
+        let mut result = Ok(());
+        for current_char in text.chars() {
+            consumed += current_char.len_utf8();
+            result = self.feed_char(current_char);
+            if result.is_err() {
+                break;
             }
         }
+        self.feed_buffer = buffer[consumed..].to_vec();
+        result?;
+        if !is_valid {
+            return Err(self.invalid_utf8_error());
+        }
+        Ok(())
+    }
+
+    /// Ends the input given by `feed`.
+    pub fn finish(&mut self) -> Result<(), ParserError> {
+        if !self.feed_buffer.is_empty() {
+            return Err(self.invalid_utf8_error());
+        }
+        if self.is_carriage_return_pending {
+            self.is_carriage_return_pending = false;
+            self.feed_line_char('\r', self.input_offset - 1)?;
+        }
+        if self.is_line_open {
+            // Position just after the last line
+            self.current_column += 1;
+        }
+        self.is_line_open = false;
+        self.is_comment_open = false;
+        self.finish_parsing()
+    }
+
+    /// In recovery mode, parsing goes on after a syntax error by skipping to
+    /// the next comma or closing bracket. All errors are collected, see
+    /// `get_errors`, and the first one is returned at the end of input. The
+    /// token vector holds everything parsed successfully.
+    pub fn set_recovery_mode(&mut self, recovery_mode: bool) -> &mut Self {
+        self.recovery_mode = recovery_mode;
+        self
+    }
+
+    pub fn is_recovery_mode(&self) -> bool {
+        self.recovery_mode
+    }
+
+    /// Errors collected in recovery mode, in order of appearance.
+    pub fn get_errors(&self) -> &[ParserError] {
+        &self.errors
+    }
+
+    /// Parses text from any reader, e.g. stdin, a pipe or a `Cursor`.
+    pub fn parse_reader<R: Read>(&mut self, reader: R) -> Result<(), AbelError> {
+        self.parse_buf_reader(io::BufReader::new(reader))
     }
 
+    /// Parses text from a buffered reader, one line at a time. Failures to
+    /// read are returned as `AbelError::Io`.
+    pub fn parse_buf_reader<R: BufRead>(&mut self, mut reader: R)
+    -> Result<(), AbelError>
+    {
+        let mut raw_line = String::new();
+        loop {
+            raw_line.clear();
+            if reader.read_line(&mut raw_line)? == 0 {
+                break;
+            }
+            self.parse_raw_line(&raw_line)?;
+        }
+        Ok(self.finish_parsing()?)
+    }
 }
 
 #[cfg(test)]
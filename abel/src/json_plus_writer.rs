@@ -0,0 +1,9 @@
+use crate::json_writer::JsonWriter;
+
+pub fn make_json_plus_writer() -> JsonWriter {
+    JsonWriter::new_plus()
+}
+
+#[cfg(test)]
+#[path = "./unittest/json_plus_writer/tests.rs"]
+mod tests;
\ No newline at end of file
@@ -4,6 +4,7 @@ use crate::error::ErrorKind;
 use crate::typefy::NamedType;
 use crate::symbol;
 use crate::token::Token;
+use crate::span::Span;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum JsonTokenType {
@@ -107,6 +108,9 @@ pub struct JsonToken {
     pub terminal_type: JsonTerminalType,
     pub literal_scheme: LiteralScheme,
     pub referenced_type: String,
+    /// Source text of the token. For an iter key, it's the text of the
+    /// element it refers to.
+    pub span: Span,
 }
 
 impl JsonToken {
@@ -123,13 +127,13 @@ impl Token for JsonToken {
     fn token_to_string(&self) -> String {
         let stem = format!(
             "Line:{}, Literal:{}, Type:{}, PK:{}, Level:{}, ContainerType:{}",
-            self.line.to_string(), self.literal, self.type_name(), self.parent_key,
-            self.level.to_string(),self.container_type.type_name());
+            self.line, self.literal, self.type_name(), self.parent_key,
+            self.level, self.container_type.type_name());
         match self.type_name() {
             "IterKey" => format!(
                 "({}, IterIndex:{}, ReferencedType:{})",
                 stem,
-                self.iter_index.to_string(),
+                self.iter_index,
                 self.referenced_type),
             "Terminal" => format!(
                 "({}, TerminalType:{}, LiteralScheme:{})",
@@ -165,13 +169,14 @@ pub fn tokenize_iter_key(
         literal: literal.to_owned(),
         token_type: JsonTokenType::IterKey,
         parent_key: parent_key.to_owned(),
-        level: level,
-        line: line,
-        container_type: container_type,
-        iter_index: iter_index,
+        level,
+        line,
+        container_type,
+        iter_index,
         terminal_type: JsonTerminalType::NotSet, // default
         literal_scheme: LiteralScheme::None, // default
-        referenced_type: "".to_owned() // default
+        referenced_type: "".to_owned(), // default
+        span: Span::default() // set by parser
     }
 }
 
@@ -189,13 +194,14 @@ pub fn tokenize_terminal(
         literal: literal.to_owned(),
         token_type: JsonTokenType::Terminal,
         parent_key: parent_key.to_owned(),
-        level: level,
-        line: line,
-        container_type: container_type,
+        level,
+        line,
+        container_type,
         iter_index: -99, // default
-        terminal_type: terminal_type,
+        terminal_type,
         literal_scheme: scheme,
-        referenced_type: "".to_owned() // default
+        referenced_type: "".to_owned(), // default
+        span: Span::default() // set by parser
     }
 }
 
@@ -211,13 +217,14 @@ pub fn tokenize_key(
         literal: literal.to_owned(),
         token_type: JsonTokenType::Key,
         parent_key: parent_key.to_owned(),
-        level: level,
-        line: line,
-        container_type: container_type,
+        level,
+        line,
+        container_type,
         iter_index: -99,
         terminal_type: JsonTerminalType::NotSet, // default
         literal_scheme: scheme,
-        referenced_type: "".to_owned() // default
+        referenced_type: "".to_owned(), // default
+        span: Span::default() // set by parser
     }
 }
 
@@ -231,15 +238,16 @@ pub fn tokenize(
 ) -> JsonToken {
     JsonToken {
         literal: literal.to_owned(),
-        token_type: token_type,
+        token_type,
         parent_key: parent_key.to_owned(),
-        level: level,
-        line: line,
-        container_type: container_type,
+        level,
+        line,
+        container_type,
         iter_index: -99,
         terminal_type: JsonTerminalType::NotSet, // default
         literal_scheme: LiteralScheme::None, // default
-        referenced_type: "".to_owned() // default
+        referenced_type: "".to_owned(), // default
+        span: Span::default() // set by parser
     }
 }
 
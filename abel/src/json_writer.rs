@@ -0,0 +1,382 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use crate::error::{Error, InternalError, ErrorKind};
+use crate::object::Object;
+use crate::marker::ScalarValued;
+use crate::bool::Bool;
+use crate::integer::Integer;
+use crate::double::Double;
+use crate::complex::Complex;
+use crate::binary::Binary;
+use crate::bitstring::Bitstring;
+use crate::text::Text;
+use crate::list::List;
+use crate::dict::Dict;
+
+/// Layout options of the text produced by `JsonWriter`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WriterFormat {
+    /// Everything on a single line without any whitespace.
+    pub compact: bool,
+    /// Number of spaces per level. Ignored if `use_tabs` is set.
+    pub indent_width: usize,
+    /// Indents with one tab per level.
+    pub use_tabs: bool,
+    /// Lists of scalars are kept on one line if they fit in `max_line_width`.
+    pub inline_short_lists: bool,
+    /// Keys sorted alphabetically, otherwise in insertion order.
+    pub sort_keys: bool,
+    /// Maximum line width for inlined lists. Only decides whether a list is
+    /// inlined, other lines aren't wrapped.
+    pub max_line_width: usize,
+}
+
+impl WriterFormat {
+    /// Multi-line layout, indented by 4 spaces.
+    pub fn pretty() -> Self {
+        WriterFormat {
+            compact: false,
+            indent_width: 4,
+            use_tabs: false,
+            inline_short_lists: true,
+            sort_keys: false,
+            max_line_width: 80,
+        }
+    }
+
+    /// Single-line layout.
+    pub fn compact() -> Self {
+        WriterFormat {
+            compact: true,
+            ..Self::pretty()
+        }
+    }
+
+    fn indent(&self, level: usize) -> String {
+        if self.use_tabs {
+            "\t".repeat(level)
+        } else {
+            " ".repeat(self.indent_width * level)
+        }
+    }
+
+    // Width of the indentation, a tab is counted as `indent_width` columns.
+    fn indent_columns(&self, level: usize) -> usize {
+        self.indent_width * level
+    }
+}
+
+pub struct JsonWriter {
+    writer_type: String,
+    format: WriterFormat,
+}
+
+/// Escapes a string according to JSON grammar. Enclosing double quotation
+/// marks are not added.
+pub fn escape_string(src: &str) -> String {
+    let mut escaped = String::with_capacity(src.len());
+    for ch in src.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            '\u{08}' => escaped.push_str("\\b"),
+            '\u{0C}' => escaped.push_str("\\f"),
+            ch if (ch as u32) < 0x20 => {
+                escaped.push_str(&format!("\\u{:04x}", ch as u32));
+            },
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+fn downcast<T>(obj: &dyn Object) -> Result<&T, InternalError>
+where
+    T: 'static + Object
+{
+    match obj.as_any().downcast_ref::<T>() {
+        Some(target) => Ok(target),
+        None => {
+            let msg = format!("Object named {} failed to match the type {}.",
+                              obj.type_name(), std::any::type_name::<T>());
+            Err(InternalError::new(&msg, ErrorKind::MismatchedType))
+        }
+    }
+}
+
+/// Private methods
+impl JsonWriter {
+    fn is_plus(&self) -> bool {
+        self.writer_type == "json_plus"
+    }
+
+    fn write_object(&self, obj: &dyn Object, level: usize, out: &mut String)
+    -> Result<(), InternalError>
+    {
+        match obj.type_name() {
+            "Null" => out.push_str("null"),
+            "Bool" => out.push_str(&downcast::<Bool>(obj)?.to_string()),
+            "Double" => self.write_double(downcast::<Double>(obj)?, out)?,
+            "Text" => self.write_text(downcast::<Text>(obj)?, out),
+            "Dict" => self.write_dict(downcast::<Dict>(obj)?, level, out)?,
+            "List" => self.write_list(downcast::<List>(obj)?, level, out)?,
+            // Types below are only available in JSON+ format
+            "Integer" if self.is_plus() => {
+                out.push_str(&downcast::<Integer>(obj)?.to_string());
+            },
+            "Complex" if self.is_plus() => {
+                self.write_complex(downcast::<Complex>(obj)?, out)?;
+            },
+            "Binary" if self.is_plus() => {
+                out.push_str(&downcast::<Binary>(obj)?.to_string());
+            },
+            "Bitstring" if self.is_plus() => {
+                out.push_str(&downcast::<Bitstring>(obj)?.to_string());
+            },
+            other => {
+                let msg = format!("Type {} cannot be written as {}.",
+                                  other, self.format_name());
+                return Err(InternalError::new(&msg, ErrorKind::IncompatibleType));
+            }
+        }
+        Ok(())
+    }
+
+    fn format_name(&self) -> &'static str {
+        if self.is_plus() { "JSON+" } else { "standard JSON" }
+    }
+
+    fn write_double(&self, double: &Double, out: &mut String)
+    -> Result<(), InternalError>
+    {
+        // Neither JSON nor JSON+ has a representation of NaN and infinities.
+        if !double.is_finite() {
+            let format = if self.is_plus() { "JSON+" } else { "standard JSON" };
+            let msg = format!("Double {} cannot be written as {}.", double, format);
+            return Err(InternalError::new(&msg, ErrorKind::IncompatibleType));
+        }
+        if self.is_plus() {
+            // Debug format always keeps a decimal point or an exponent, so
+            // that the value isn't read back as an Integer.
+            out.push_str(&format!("{:?}", double.value()));
+            return Ok(());
+        }
+        // Debug format is the shortest round trip text and switches to an
+        // exponent for large and small magnitudes; JSON doesn't need the
+        // trailing ".0" of whole numbers.
+        let text = format!("{:?}", double.value());
+        out.push_str(text.strip_suffix(".0").unwrap_or(&text));
+        Ok(())
+    }
+
+    /// Complex is written as `real+imagj`.
+    fn write_complex(&self, complex: &Complex, out: &mut String)
+    -> Result<(), InternalError>
+    {
+        if !(complex.real().is_finite() && complex.imag().is_finite()) {
+            let msg = format!("Complex {} cannot be written as JSON+.", complex);
+            return Err(InternalError::new(&msg, ErrorKind::IncompatibleType));
+        }
+        out.push_str(&complex.to_json_plus());
+        Ok(())
+    }
+
+    fn write_text(&self, text: &Text, out: &mut String) {
+        out.push('"');
+        out.push_str(&escape_string(text));
+        out.push('"');
+    }
+
+    fn new_line(&self, level: usize, out: &mut String) {
+        out.push('\n');
+        out.push_str(&self.format.indent(level));
+    }
+
+    fn write_dict(&self, dict: &Dict, level: usize, out: &mut String)
+    -> Result<(), InternalError>
+    {
+        let entries = if self.format.sort_keys {
+            dict.sorted_entries()
+        } else {
+            dict.entries()
+        };
+        out.push('{');
+        for (index, (key, value)) in entries.iter().enumerate() {
+            if index > 0 {
+                out.push(',');
+            }
+            if !self.format.compact {
+                self.new_line(level + 1, out);
+            }
+            out.push('"');
+            out.push_str(&escape_string(key));
+            out.push_str(if self.format.compact { "\":" } else { "\": " });
+            self.write_object(*value, level + 1, out)?;
+        }
+        if !self.format.compact && !entries.is_empty() {
+            self.new_line(level, out);
+        }
+        out.push('}');
+        Ok(())
+    }
+
+    fn write_list(&self, list: &List, level: usize, out: &mut String)
+    -> Result<(), InternalError>
+    {
+        if !self.format.compact && self.format.inline_short_lists {
+            if let Some(inline) = self.inline_list(list, level, out)? {
+                out.push_str(&inline);
+                return Ok(());
+            }
+        }
+        out.push('[');
+        for (index, item) in list.iter().enumerate() {
+            if index > 0 {
+                out.push(',');
+            }
+            if !self.format.compact {
+                self.new_line(level + 1, out);
+            }
+            self.write_object(item.as_ref(), level + 1, out)?;
+        }
+        if !self.format.compact && !list.is_empty() {
+            self.new_line(level, out);
+        }
+        out.push(']');
+        Ok(())
+    }
+
+    /// Single-line form of a list holding only scalars, if it fits in the
+    /// maximum line width together with the current line.
+    fn inline_list(&self, list: &List, level: usize, out: &str)
+    -> Result<Option<String>, InternalError>
+    {
+        if list.iter().any(|item| item.type_name() == "Dict"
+                                  || item.type_name() == "List") {
+            return Ok(None);
+        }
+        let mut items = Vec::<String>::new();
+        for item in list.iter() {
+            let mut item_string = String::new();
+            self.write_object(item.as_ref(), level + 1, &mut item_string)?;
+            items.push(item_string);
+        }
+        let inline = format!("[{}]", items.join(", "));
+        // Current line, with tabs counted by their columns
+        let line = &out[out.rfind('\n').map_or(0, |pos| pos + 1)..];
+        let line_width = self.format.indent_columns(level)
+            + line.trim_start_matches(['\t', ' ']).chars().count();
+        // One more column for a possible trailing comma
+        if line_width + inline.chars().count() < self.format.max_line_width {
+            Ok(Some(inline))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Public methods
+impl JsonWriter {
+    pub fn write_to_string(&self, obj: &dyn Object)
+    -> Result<String, InternalError>
+    {
+        let mut out = String::new();
+        self.write_object(obj, 0, &mut out)?;
+        Ok(out)
+    }
+
+    pub fn write_to<W: Write>(&self, obj: &dyn Object, writer: &mut W)
+    -> Result<(), InternalError>
+    {
+        let out = self.write_to_string(obj)?;
+        if let Err(why) = writer.write_all(out.as_bytes()) {
+            let msg = format!("Failed to write {} text: {}", self.writer_type, why);
+            return Err(InternalError::new(&msg, ErrorKind::FailedToWrite));
+        }
+        Ok(())
+    }
+
+    pub fn write_to_file(&self, obj: &dyn Object, filename: &str)
+    -> Result<(), InternalError>
+    {
+        let path = Path::new(filename);
+        match File::create(path) {
+            Err(why) => {
+                let msg = format!("Failed to create {}: {}", path.display(), why);
+                Err(InternalError::new(&msg, ErrorKind::FailedToWrite))
+            },
+            Ok(mut file) => self.write_to(obj, &mut file),
+        }
+    }
+
+    pub fn get_writer_type(&self) -> &str {
+        &self.writer_type
+    }
+
+    pub fn get_format(&self) -> &WriterFormat {
+        &self.format
+    }
+
+    pub fn set_format(&mut self, format: WriterFormat) -> &mut Self {
+        self.format = format;
+        self
+    }
+}
+
+/// Public methods
+impl JsonWriter {
+    pub fn new() -> Self {
+        Self {
+            writer_type: String::from("json"),
+            format: WriterFormat::default()
+        }
+    }
+
+    // Returns a writer for JSON plus format
+    pub fn new_plus() -> Self {
+        Self {
+            writer_type: String::from("json_plus"),
+            format: WriterFormat::default()
+        }
+    }
+}
+
+/// Same as the format of a new `JsonWriter`.
+impl Default for WriterFormat {
+    fn default() -> Self {
+        Self::compact()
+    }
+}
+
+impl Default for JsonWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Writes an object as standard JSON text in the given format.
+pub fn to_json_string(obj: &dyn Object, format: &WriterFormat)
+-> Result<String, InternalError>
+{
+    let mut writer = JsonWriter::new();
+    writer.set_format(format.clone());
+    writer.write_to_string(obj)
+}
+
+/// Writes an object as JSON+ text in the given format.
+pub fn to_json_plus_string(obj: &dyn Object, format: &WriterFormat)
+-> Result<String, InternalError>
+{
+    let mut writer = JsonWriter::new_plus();
+    writer.set_format(format.clone());
+    writer.write_to_string(obj)
+}
+
+#[cfg(test)]
+#[path = "./unittest/json_writer/tests.rs"]
+mod tests;
@@ -17,11 +17,16 @@ pub mod bitstring;
 pub mod container;
 pub mod list;
 pub mod dict;
+pub mod pointer;
+pub mod query;
+// Comparison
+pub mod equality;
 // Utility crates
 pub mod symbol;
 pub mod util;
 pub mod converter;
 pub mod delimiter;
+pub mod span;
 // Tokens
 pub mod token;
 pub mod json_token;
@@ -31,4 +36,12 @@ pub mod json_parser;
 pub mod json_plus_parser;
 // Loaders
 pub mod json_loader;
-pub mod json_plus_loader;
\ No newline at end of file
+pub mod json_plus_loader;
+// Typed conversion
+pub mod from_abel;
+pub mod into_abel;
+#[cfg(feature = "serde")]
+pub mod serde_support;
+// Writers
+pub mod json_writer;
+pub mod json_plus_writer;
\ No newline at end of file
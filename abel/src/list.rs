@@ -1,4 +1,6 @@
+use std::fmt;
 use std::any::Any;
+use std::hash::{Hash, Hasher};
 use std::ops::{Index, IndexMut};
 use std::ops::Deref;
 
@@ -7,11 +9,20 @@ use crate::object::Object;
 use crate::marker::ScalarLikePrimitive;
 use crate::typefy::IntoType;
 use crate::container::Container;
+use crate::equality;
+use crate::pointer;
+use crate::query::{self, Match};
 
 pub struct List {
     internal: Vec<Box<dyn Object>>,
 }
 
+impl Default for List {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl List {
     pub fn new() -> List {
         List {
@@ -36,6 +47,64 @@ impl List {
         self.internal.len()
     }
 
+    /// Deep copy of the list. Fails if any element, at any depth, doesn't
+    /// support cloning.
+    pub fn try_clone(&self) -> Result<List, InternalError> {
+        let mut internal = Vec::<Box<dyn Object>>::new();
+        for item in self.internal.iter() {
+            internal.push(item.clone_box()?);
+        }
+        Ok(List {
+            internal
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.internal.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.internal.clear();
+    }
+
+    pub fn pop(&mut self) -> Result<Box<dyn Object>, InternalError> {
+        match self.internal.pop() {
+            Some(obj_boxed) => Ok(obj_boxed),
+            None => Err(InternalError::new(
+                "Cannot pop from an empty list.",
+                ErrorKind::IndexOutOfRange
+            ))
+        }
+    }
+
+    /// Shortens the list to `len` elements, no effect if `len` is greater
+    /// than the current length.
+    pub fn truncate(&mut self, len: usize) {
+        self.internal.truncate(len);
+    }
+
+    pub fn swap(&mut self, a: usize, b: usize) -> Result<(), InternalError> {
+        if a >= self.len() || b >= self.len() {
+            let msg = format!("Index {} or {} for swapping is out of range.", a, b);
+            Err(InternalError::new(&msg, ErrorKind::IndexOutOfRange))
+        } else {
+            self.internal.swap(a, b);
+            Ok(())
+        }
+    }
+
+    /// Keeps only the elements for which the predicate returns true.
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&Box<dyn Object>) -> bool
+    {
+        self.internal.retain(f);
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Box<dyn Object>> {
+        self.internal.iter_mut()
+    }
+
     pub fn push<T>(&mut self, obj: T)
     where
         T: 'static + Object
@@ -95,6 +164,49 @@ impl List {
     }
 }
 
+/// Access by JSON Pointer, e.g. `get_path::<Double>("/2/port")`
+impl List {
+    pub fn get_path<TargetType>(&self, path: &str) -> Result<&TargetType, InternalError>
+    where
+        TargetType: 'static + Object
+    {
+        pointer::get_path(self, path)
+    }
+
+    pub fn get_path_mut<TargetType>(&mut self, path: &str)
+    -> Result<&mut TargetType, InternalError>
+    where
+        TargetType: 'static + Object
+    {
+        pointer::get_path_mut(self, path)
+    }
+
+    /// Sets a value, creating missing containers on the way. Gaps in lists
+    /// are filled with nulls, at most `pointer::MAX_LIST_GAP` of them, and
+    /// `-` appends to a list.
+    pub fn set_path<SourceType>(&mut self, path: &str, obj: SourceType)
+    -> Result<&mut Self, InternalError>
+    where
+        SourceType: 'static + Object
+    {
+        self.set_path_box(path, Box::new(obj))
+    }
+
+    pub fn set_path_box(&mut self, path: &str, obj_boxed: Box<dyn Object>)
+    -> Result<&mut Self, InternalError>
+    {
+        pointer::set_path(self, path, obj_boxed)?;
+        Ok(self)
+    }
+}
+
+/// JSONPath queries, e.g. `query("$[?(@.enabled == true)].host")`
+impl List {
+    pub fn query(&self, expr: &str) -> Result<Vec<Match<'_>>, InternalError> {
+        query::select(self, expr)
+    }
+}
+
 impl Object for List {
     fn as_any(&self) -> &dyn Any {
         self
@@ -107,6 +219,39 @@ impl Object for List {
     fn type_name(&self) -> &'static str {
         "List"
     }
+
+    fn clone_box(&self) -> Result<Box<dyn Object>, InternalError> {
+        Ok(Box::new(self.try_clone()?))
+    }
+
+    fn fmt_display(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+
+    fn fmt_debug(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// JSON+ like form, `[value, ...]`
+impl fmt::Display for List {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[")?;
+        for (index, item) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", item)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl fmt::Debug for List {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "List ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
 }
 
 /// Specialise `Container` trait for List, setting key type to `usize`
@@ -210,6 +355,15 @@ impl Container<usize> for List {
             Ok(self)
         }
     }
+
+    fn remove(&mut self, index: usize) -> Result<Box<dyn Object>, InternalError> {
+        if index < self.len() {
+            Ok(self.internal.remove(index))
+        } else {
+            let msg = format!("Requested index {} is out of range.", index);
+            Err(InternalError::new(&msg, ErrorKind::IndexOutOfRange))
+        }
+    }
 }
 
 impl Deref for List {
@@ -234,6 +388,57 @@ impl IndexMut<usize> for List {
     }
 }
 
+impl<'a> IntoIterator for &'a List {
+    type Item = &'a Box<dyn Object>;
+    type IntoIter = std::slice::Iter<'a, Box<dyn Object>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.internal.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut List {
+    type Item = &'a mut Box<dyn Object>;
+    type IntoIter = std::slice::IterMut<'a, Box<dyn Object>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.internal.iter_mut()
+    }
+}
+
+impl IntoIterator for List {
+    type Item = Box<dyn Object>;
+    type IntoIter = std::vec::IntoIter<Box<dyn Object>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.internal.into_iter()
+    }
+}
+
+impl FromIterator<Box<dyn Object>> for List {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Box<dyn Object>>
+    {
+        List {
+            internal: iter.into_iter().collect()
+        }
+    }
+}
+
+/// Deep equality, see `equality::deep_eq`.
+impl PartialEq for List {
+    fn eq(&self, other: &Self) -> bool {
+        equality::deep_eq(self, other)
+    }
+}
+
+impl Hash for List {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        equality::deep_hash(self, state);
+    }
+}
+
 #[cfg(test)]
 #[path = "./unittest/list/tests.rs"]
 mod tests;
\ No newline at end of file
@@ -1,15 +1,22 @@
 use std::fmt;
 use std::any::Any;
+use std::str::FromStr;
 
 use crate::error::{Error, InternalError, ErrorKind};
 use crate::object::Object;
 use crate::marker::ScalarValued;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Null {
     internal: i32,
 }
 
+impl Default for Null {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Null {
     pub fn new() -> Self {
         Null {
@@ -17,7 +24,15 @@ impl Null {
         }
     }
 
-    pub fn from_str(src_str: &str) -> Result<Self, InternalError> {
+    pub fn as_str(&self) -> &'static str {
+        "null"
+    }
+}
+
+impl FromStr for Null {
+    type Err = InternalError;
+
+    fn from_str(src_str: &str) -> Result<Self, Self::Err> {
         match src_str {
             "null" => Ok(Self::new()),
             _ => Err(InternalError::new(
@@ -26,10 +41,6 @@ impl Null {
             ))
         }
     }
-
-    pub fn as_str(&self) -> &'static str {
-        "null"
-    }
 }
 
 impl Object for Null {
@@ -44,6 +55,18 @@ impl Object for Null {
     fn type_name(&self) -> &'static str {
         "Null"
     }
+
+    fn clone_box(&self) -> Result<Box<dyn Object>, InternalError> {
+        Ok(Box::new(self.clone()))
+    }
+
+    fn fmt_display(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+
+    fn fmt_debug(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
 }
 
 impl ScalarValued<i32> for Null {
@@ -54,7 +77,7 @@ impl ScalarValued<i32> for Null {
 
 impl fmt::Display for Null {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", "null")
+        write!(f, "null")
     }
 }
 
@@ -1,4 +1,9 @@
+use std::fmt;
 use std::any::Any;
+use std::hash::{Hash, Hasher};
+
+use crate::error::{Error, InternalError, ErrorKind};
+use crate::equality;
 
 pub trait Object {
     fn as_any(&self) -> &dyn Any;
@@ -6,6 +11,33 @@ pub trait Object {
     fn as_mut_any(&mut self) -> &mut dyn Any;
 
     fn type_name(&self) -> &'static str;
+
+    /// Deep copy behind a box. User-defined types opt in by overriding it,
+    /// typically with `Ok(Box::new(self.clone()))`.
+    fn clone_box(&self) -> Result<Box<dyn Object>, InternalError> {
+        let msg = format!("Type {} doesn't support cloning.", self.type_name());
+        Err(InternalError::new(&msg, ErrorKind::IncompatibleType))
+    }
+
+    /// Structural comparison of user-defined types, used by
+    /// `equality::deep_eq` when both sides have the same type name.
+    fn equals(&self, _other: &dyn Object) -> bool {
+        false
+    }
+
+    /// Hashing of user-defined types, used by `equality::deep_hash`. It
+    /// must agree with `equals`.
+    fn hash_into(&self, _state: &mut dyn Hasher) {}
+
+    /// Readable form, as the object appears in a container's `Display`.
+    fn fmt_display(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "<{}>", self.type_name())
+    }
+
+    /// Debug form, showing the type name.
+    fn fmt_debug(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.type_name())
+    }
 }
 
 impl Object for Box<dyn Object> {
@@ -20,4 +52,51 @@ impl Object for Box<dyn Object> {
     fn type_name(&self) -> &'static str {
         self.as_ref().type_name()
     }
-}
\ No newline at end of file
+
+    fn clone_box(&self) -> Result<Box<dyn Object>, InternalError> {
+        self.as_ref().clone_box()
+    }
+
+    fn equals(&self, other: &dyn Object) -> bool {
+        self.as_ref().equals(other)
+    }
+
+    fn hash_into(&self, state: &mut dyn Hasher) {
+        self.as_ref().hash_into(state)
+    }
+
+    fn fmt_display(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_ref().fmt_display(f)
+    }
+
+    fn fmt_debug(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_ref().fmt_debug(f)
+    }
+}
+
+/// Also reached by `Box<dyn Object>`, see `Object::fmt_display`.
+impl<'a> fmt::Display for dyn Object + 'a {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_display(f)
+    }
+}
+
+/// Also reached by `Box<dyn Object>`, see `Object::fmt_debug`.
+impl<'a> fmt::Debug for dyn Object + 'a {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_debug(f)
+    }
+}
+
+/// Deep equality, see `equality::deep_eq`.
+impl PartialEq for Box<dyn Object> {
+    fn eq(&self, other: &Self) -> bool {
+        equality::deep_eq(self.as_ref(), other.as_ref())
+    }
+}
+
+impl Hash for Box<dyn Object> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        equality::deep_hash(self.as_ref(), state);
+    }
+}
@@ -1,13 +1,20 @@
 use crate::error::{ErrorKind, Error, ParserError};
 use crate::delimiter;
 use crate::token::Token;
+use crate::span::{Position, Span};
 
 pub struct Parser<TokenT, ContainerT, SchemeT> {
     pub token_vector: Vec<TokenT>,
     pub current_line: i32,
     pub current_column: i32,
+    // Byte offsets of the current character and of the next unread input.
+    pub current_offset: usize,
+    pub input_offset: usize,
     // latest_symbol: String,
     pub current_literal: String,
+    // Source text of the current literal, including quotation marks.
+    pub literal_start: Option<Position>,
+    pub literal_end: Position,
     pub current_level: i32,
     pub deepest_level: i32,
     pub current_container_type: Vec<ContainerT>,
@@ -16,7 +23,26 @@ pub struct Parser<TokenT, ContainerT, SchemeT> {
     pub keys_per_level: Vec<Vec<TokenT>>,
     pub latest_syntactic_operator: String,
     pub is_escaping: bool,
+    // Characters of an unfinished `\uXXXX` escape, after the back slash.
+    pub escape_sequence: String,
+    // High surrogate waiting for its low half.
+    pub pending_surrogate: Option<u32>,
+    // Rest of the current line is a comment.
+    pub is_comment_open: bool,
+    // Bytes of chunked input not parsed yet, i.e. an incomplete character or
+    // the input after an error.
+    pub feed_buffer: Vec<u8>,
+    pub is_line_open: bool,
+    pub is_carriage_return_pending: bool,
+    // Error recovery, see `JsonParser::set_recovery_mode`
+    pub recovery_mode: bool,
+    pub errors: Vec<ParserError>,
+    // Nesting depth of skipped containers while resynchronising
+    pub skip_depth: Option<i32>,
     pub is_delimited_string_open: bool,
+    // A value ended, by a space or a closing bracket, and a comma, colon or
+    // closing bracket must come next.
+    pub is_value_closed: bool,
     pub current_literal_scheme: SchemeT,
     pub bracket_match: delimiter::DelimiterMatch,
     pub parser_type: String,
@@ -37,6 +63,23 @@ where
 
     pub fn reset_current_literal(&mut self) {
         self.current_literal.clear();
+        self.literal_start = None;
+    }
+
+    pub fn get_current_position(&self) -> Position {
+        Position::new(self.current_line, self.current_column, self.current_offset)
+    }
+
+    /// Span of the current literal, or of the current character if no
+    /// literal has been collected.
+    pub fn get_literal_span(&self) -> Span {
+        match self.literal_start {
+            Some(start) => Span::new(start, self.literal_end),
+            None => {
+                let start = self.get_current_position();
+                Span::new(start, start)
+            }
+        }
     }
 
     pub fn get_token_vector(&self) -> &Vec<TokenT> {
@@ -71,7 +114,7 @@ where
     }
 
     pub fn is_first_noncomment_character(&self) -> bool {
-        self.current_literal == "" && self.token_vector.len() == 0
+        self.current_literal.is_empty() && self.token_vector.is_empty()
     }
 
     pub fn illegal_first_noncomment_character(&self, first_char: &str)
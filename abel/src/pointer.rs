@@ -0,0 +1,291 @@
+//! JSON Pointer (RFC 6901) access into nested containers, e.g.
+//! `/servers/2/port`. The leading slash may be omitted, and `~1` and `~0`
+//! stand for `/` and `~` in keys. When setting, `-` appends to a list.
+
+use crate::error::{Error, InternalError, ErrorKind};
+use crate::object::Object;
+use crate::null::Null;
+use crate::container::Container;
+use crate::list::List;
+use crate::dict::Dict;
+
+/// Largest number of nulls inserted before a value set past the end of a
+/// list. Indices further out are out of range.
+pub const MAX_LIST_GAP: usize = 1024;
+
+/// Reference tokens of a pointer, unescaped. The empty pointer refers to
+/// the container itself and has no tokens.
+pub fn split_pointer(path: &str) -> Result<Vec<String>, InternalError> {
+    if path.is_empty() {
+        return Ok(Vec::new());
+    }
+    let path = path.strip_prefix('/').unwrap_or(path);
+    path.split('/').map(unescape_token).collect()
+}
+
+fn unescape_token(token: &str) -> Result<String, InternalError> {
+    let mut out = String::new();
+    let mut chars = token.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '~' {
+            out.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('0') => out.push('~'),
+            Some('1') => out.push('/'),
+            _ => {
+                let msg = format!("Invalid escape in path segment \"{}\", only \
+                                  ~0 and ~1 are allowed.", token);
+                return Err(InternalError::new(&msg, ErrorKind::InvalidInput));
+            }
+        }
+    }
+    Ok(out)
+}
+
+pub(crate) fn escape_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Location of the first `count` tokens, for error messages.
+pub(crate) fn location(tokens: &[String], count: usize) -> String {
+    tokens[..count].iter().map(|token| format!("/{}", escape_token(token))).collect()
+}
+
+/// Index into a list of length `len`. `-`, the element after the last one,
+/// is only valid when `allow_end` is set.
+pub(crate) fn parse_index(token: &str, len: usize, allow_end: bool, at: &str)
+-> Result<usize, InternalError>
+{
+    if token == "-" && allow_end {
+        return Ok(len);
+    }
+    if !is_index(token) {
+        return Err(not_an_index(token, at));
+    }
+    match token.parse::<usize>() {
+        Ok(index) if index < len || (allow_end && index == len) => Ok(index),
+        _ => {
+            let msg = format!("Index {} at \"{}\" is out of range, the list has {} \
+                              elements.", token, at, len);
+            Err(InternalError::new(&msg, ErrorKind::IndexOutOfRange))
+        }
+    }
+}
+
+fn is_index(token: &str) -> bool {
+    !token.is_empty()
+        && token.chars().all(|ch| ch.is_ascii_digit())
+        && (token == "0" || !token.starts_with('0'))
+}
+
+fn not_an_index(token: &str, at: &str) -> InternalError {
+    let msg = format!("Segment \"{}\" at \"{}\" is not a list index.", token, at);
+    InternalError::new(&msg, ErrorKind::InvalidInput)
+}
+
+pub(crate) fn not_a_container(obj: &dyn Object, at: &str) -> InternalError {
+    let msg = format!("Value at \"{}\" is of type {}, not a container.", at, obj.type_name());
+    InternalError::new(&msg, ErrorKind::MismatchedType)
+}
+
+fn key_not_found(key: &str, at: &str) -> InternalError {
+    let msg = format!("Key \"{}\" not found at \"{}\".", key, at);
+    InternalError::new(&msg, ErrorKind::KeyNotFound)
+}
+
+fn child<'a>(obj: &'a dyn Object, tokens: &[String], depth: usize)
+-> Result<&'a dyn Object, InternalError>
+{
+    let token = &tokens[depth];
+    let at = location(tokens, depth);
+    if let Some(dict) = obj.as_any().downcast_ref::<Dict>() {
+        dict.get(token).ok_or_else(|| key_not_found(token, &at))
+    } else if let Some(list) = obj.as_any().downcast_ref::<List>() {
+        let index = parse_index(token, list.len(), false, &at)?;
+        Ok(list[index].as_ref())
+    } else {
+        Err(not_a_container(obj, &at))
+    }
+}
+
+fn child_mut<'a>(obj: &'a mut dyn Object, tokens: &[String], depth: usize)
+-> Result<&'a mut dyn Object, InternalError>
+{
+    let token = &tokens[depth];
+    let at = location(tokens, depth);
+    if obj.as_any().is::<Dict>() {
+        let dict = obj.as_mut_any().downcast_mut::<Dict>().unwrap();
+        match dict.get_mut(token) {
+            Some(value) => Ok(value.as_mut()),
+            None => Err(key_not_found(token, &at)),
+        }
+    } else if obj.as_any().is::<List>() {
+        let list = obj.as_mut_any().downcast_mut::<List>().unwrap();
+        let index = parse_index(token, list.len(), false, &at)?;
+        Ok(list[index].as_mut())
+    } else {
+        Err(not_a_container(obj, &at))
+    }
+}
+
+fn mismatched_type<TargetType>(obj: &dyn Object, at: &str) -> InternalError {
+    let msg = format!("Value at \"{}\" is of type {}, failed to match the \
+                      requested type {}.",
+                      at, obj.type_name(), std::any::type_name::<TargetType>());
+    InternalError::new(&msg, ErrorKind::MismatchedType)
+}
+
+pub(crate) fn get_path<'a, TargetType>(root: &'a dyn Object, path: &str)
+-> Result<&'a TargetType, InternalError>
+where
+    TargetType: 'static + Object
+{
+    let tokens = split_pointer(path)?;
+    let mut obj = root;
+    for depth in 0..tokens.len() {
+        obj = child(obj, &tokens, depth)?;
+    }
+    obj.as_any().downcast_ref::<TargetType>()
+        .ok_or_else(|| mismatched_type::<TargetType>(obj, &location(&tokens, tokens.len())))
+}
+
+pub(crate) fn get_path_mut<'a, TargetType>(root: &'a mut dyn Object, path: &str)
+-> Result<&'a mut TargetType, InternalError>
+where
+    TargetType: 'static + Object
+{
+    let tokens = split_pointer(path)?;
+    let mut obj = root;
+    for depth in 0..tokens.len() {
+        obj = child_mut(obj, &tokens, depth)?;
+    }
+    if !obj.as_any().is::<TargetType>() {
+        return Err(mismatched_type::<TargetType>(obj, &location(&tokens, tokens.len())));
+    }
+    Ok(obj.as_mut_any().downcast_mut::<TargetType>().unwrap())
+}
+
+/// Position to set in a list of length `len`, possibly past its end.
+fn list_slot(token: &str, len: usize) -> Option<usize> {
+    if token == "-" {
+        Some(len)
+    } else if is_index(token) {
+        token.parse::<usize>().ok()
+    } else {
+        None
+    }
+}
+
+fn check_gap(index: usize, len: usize, at: &str) -> Result<(), InternalError> {
+    if index > len.saturating_add(MAX_LIST_GAP) {
+        let msg = format!("Index {} at \"{}\" is out of range, the list has {} elements \
+                          and at most {} nulls are filled in.", index, at, len, MAX_LIST_GAP);
+        return Err(InternalError::new(&msg, ErrorKind::IndexOutOfRange));
+    }
+    Ok(())
+}
+
+fn has_child(obj: &dyn Object, token: &str) -> bool {
+    if let Some(dict) = obj.as_any().downcast_ref::<Dict>() {
+        dict.has_key(token)
+    } else if let Some(list) = obj.as_any().downcast_ref::<List>() {
+        list_slot(token, list.len()).is_some_and(|index| index < list.len())
+    } else {
+        false
+    }
+}
+
+// Stores `value` under the token at `depth`, replacing an existing value.
+// Gaps in lists are filled with nulls.
+fn set_child<'a>(obj: &'a mut dyn Object, tokens: &[String], depth: usize,
+                 value: Box<dyn Object>)
+-> Result<&'a mut dyn Object, InternalError>
+{
+    let token = &tokens[depth];
+    let at = location(tokens, depth);
+    if obj.as_any().is::<Dict>() {
+        let dict = obj.as_mut_any().downcast_mut::<Dict>().unwrap();
+        if dict.has_key(token) {
+            dict.insert_box(token, value);
+        } else {
+            dict.set_box(token, value)?;
+        }
+        Ok(dict.get_mut(token).unwrap().as_mut())
+    } else if obj.as_any().is::<List>() {
+        let list = obj.as_mut_any().downcast_mut::<List>().unwrap();
+        let index = list_slot(token, list.len()).ok_or_else(|| not_an_index(token, &at))?;
+        if index < list.len() {
+            list[index] = value;
+        } else {
+            while list.len() < index {
+                list.set_box(list.len(), Box::new(Null::new()))?;
+            }
+            list.set_box(index, value)?;
+        }
+        Ok(list[index].as_mut())
+    } else {
+        Err(not_a_container(obj, &at))
+    }
+}
+
+// Checks the part of the path that already exists, so that a failing call
+// leaves the document untouched.
+fn check_set_path(root: &dyn Object, tokens: &[String]) -> Result<(), InternalError> {
+    let mut obj = root;
+    for depth in 0..tokens.len() {
+        let at = location(tokens, depth);
+        let token = &tokens[depth];
+        if let Some(list) = obj.as_any().downcast_ref::<List>() {
+            let index = list_slot(token, list.len()).ok_or_else(|| not_an_index(token, &at))?;
+            check_gap(index, list.len(), &at)?;
+        } else if !obj.as_any().is::<Dict>() {
+            return Err(not_a_container(obj, &at));
+        }
+        if !has_child(obj, token) {
+            // The containers created for the remaining tokens are empty
+            for depth in depth + 1..tokens.len() {
+                if let Some(index) = list_slot(&tokens[depth], 0) {
+                    check_gap(index, 0, &location(tokens, depth))?;
+                }
+            }
+            return Ok(());
+        }
+        obj = child(obj, tokens, depth)?;
+    }
+    Ok(())
+}
+
+/// Missing containers on the way are created, a list when the next token is
+/// an index and a dictionary otherwise.
+pub(crate) fn set_path(root: &mut dyn Object, path: &str, value: Box<dyn Object>)
+-> Result<(), InternalError>
+{
+    let tokens = split_pointer(path)?;
+    if tokens.is_empty() {
+        let msg = "Empty path refers to the container itself, which cannot be set.";
+        return Err(InternalError::new(msg, ErrorKind::InvalidInput));
+    }
+    check_set_path(root, &tokens)?;
+    let last = tokens.len() - 1;
+    let mut obj = root;
+    for depth in 0..last {
+        obj = if has_child(obj, &tokens[depth]) {
+            child_mut(obj, &tokens, depth)?
+        } else {
+            let container: Box<dyn Object> = match list_slot(&tokens[depth + 1], 0) {
+                Some(_) => Box::new(List::new()),
+                None => Box::new(Dict::new()),
+            };
+            set_child(obj, &tokens, depth, container)?
+        };
+    }
+    set_child(obj, &tokens, last, value)?;
+    Ok(())
+}
+
+// Unittest
+#[cfg(test)]
+#[path = "./unittest/pointer/tests.rs"]
+mod tests;
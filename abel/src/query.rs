@@ -0,0 +1,605 @@
+//! JSONPath queries over loaded documents, e.g.
+//!
+//! ```text
+//! $.servers[*].host            hosts of all servers
+//! $..port                      every key "port", at any depth
+//! $.servers[1:3]               slice of a list, [start:end:step]
+//! $.servers[-1]                last element
+//! $.servers[?(@.enabled == true && @.port >= 1024)].host
+//! ```
+//!
+//! Names can also be written as `['name']`, and several selectors can be
+//! combined as `[0, 2]`. Filters support `@` (the element) and `$` (the
+//! document), comparisons, `!`, `&&`, `||`, parentheses and existence tests
+//! such as `?(@.tls)`. Matches are returned in document order.
+
+use crate::error::{Error, InternalError, ErrorKind};
+use crate::object::Object;
+use crate::null::Null;
+use crate::bool::Bool;
+use crate::integer::Integer;
+use crate::double::Double;
+use crate::text::Text;
+use crate::list::List;
+use crate::dict::Dict;
+use crate::pointer::escape_token;
+
+/// A value matched by a query, with its location as a JSON Pointer.
+pub struct Match<'a> {
+    pub path: String,
+    pub value: &'a dyn Object,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Selector {
+    Name(String),
+    Wildcard,
+    Index(i64),
+    Slice(Option<i64>, Option<i64>, i64),
+    Filter(Expr),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Segment {
+    recursive: bool,
+    selectors: Vec<Selector>,
+}
+
+/// Singular path in a filter, made of names and indices.
+#[derive(Debug, Clone, PartialEq)]
+struct RelativePath {
+    from_root: bool,
+    steps: Vec<Selector>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Literal {
+    Null,
+    Bool(bool),
+    Number(f64),
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Operand {
+    Path(RelativePath),
+    Literal(Literal),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Or(Box<Expr>, Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Exists(RelativePath),
+    Compare(Operand, Comparison, Operand),
+}
+
+/// Compiled query, reusable across documents.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    segments: Vec<Segment>,
+}
+
+impl Query {
+    pub fn parse(expr: &str) -> Result<Query, InternalError> {
+        let mut parser = QueryParser {
+            src: expr,
+            chars: expr.chars().collect(),
+            pos: 0,
+        };
+        parser.parse_query()
+    }
+
+    pub fn select<'a>(&self, root: &'a dyn Object) -> Vec<Match<'a>> {
+        let mut nodes = vec![Match { path: String::new(), value: root }];
+        for segment in &self.segments {
+            let mut next = Vec::new();
+            for node in &nodes {
+                if segment.recursive {
+                    let mut descendants = Vec::new();
+                    collect_descendants(node, &mut descendants);
+                    for descendant in &descendants {
+                        apply_selectors(&segment.selectors, descendant, root, &mut next);
+                    }
+                } else {
+                    apply_selectors(&segment.selectors, node, root, &mut next);
+                }
+            }
+            nodes = next;
+        }
+        nodes
+    }
+}
+
+/// Parses and runs a query in one go.
+pub fn select<'a>(root: &'a dyn Object, expr: &str) -> Result<Vec<Match<'a>>, InternalError> {
+    Ok(Query::parse(expr)?.select(root))
+}
+
+// Evaluation
+
+fn child_path(path: &str, token: &str) -> String {
+    format!("{}/{}", path, escape_token(token))
+}
+
+// Direct children in document order.
+fn children<'a>(node: &Match<'a>) -> Vec<Match<'a>> {
+    let any = node.value.as_any();
+    if let Some(dict) = any.downcast_ref::<Dict>() {
+        dict.iter()
+            .map(|(key, value)| Match { path: child_path(&node.path, key), value: value.as_ref() })
+            .collect()
+    } else if let Some(list) = any.downcast_ref::<List>() {
+        list.iter().enumerate()
+            .map(|(index, value)| Match {
+                path: child_path(&node.path, &index.to_string()),
+                value: value.as_ref()
+            })
+            .collect()
+    } else {
+        Vec::new()
+    }
+}
+
+// The node itself and all nodes below it, in pre-order.
+fn collect_descendants<'a>(node: &Match<'a>, out: &mut Vec<Match<'a>>) {
+    out.push(Match { path: node.path.clone(), value: node.value });
+    for child in children(node) {
+        collect_descendants(&child, out);
+    }
+}
+
+fn normalize_index(index: i64, len: usize) -> Option<usize> {
+    let index = if index < 0 { index + len as i64 } else { index };
+    if index >= 0 && (index as usize) < len {
+        Some(index as usize)
+    } else {
+        None
+    }
+}
+
+// Indices of a slice, as in Python.
+fn slice_indices(start: Option<i64>, end: Option<i64>, step: i64, len: usize) -> Vec<usize> {
+    let len = len as i64;
+    let bound = |index: i64, low: i64, high: i64| {
+        let index = if index < 0 { index + len } else { index };
+        index.clamp(low, high)
+    };
+    let mut indices = Vec::new();
+    if step > 0 {
+        let mut index = bound(start.unwrap_or(0), 0, len);
+        let end = bound(end.unwrap_or(len), 0, len);
+        while index < end {
+            indices.push(index as usize);
+            index = match index.checked_add(step) {
+                Some(index) => index,
+                None => break,
+            };
+        }
+    } else if step < 0 {
+        let mut index = bound(start.unwrap_or(len - 1), -1, len - 1);
+        let end = match end {
+            Some(end) => bound(end, -1, len - 1),
+            None => -1,
+        };
+        while index > end {
+            indices.push(index as usize);
+            index = match index.checked_add(step) {
+                Some(index) => index,
+                None => break,
+            };
+        }
+    }
+    indices
+}
+
+fn apply_selectors<'a>(
+    selectors: &[Selector],
+    node: &Match<'a>,
+    root: &'a dyn Object,
+    out: &mut Vec<Match<'a>>
+) {
+    let any = node.value.as_any();
+    for selector in selectors {
+        match selector {
+            Selector::Name(name) => {
+                if let Some(value) = any.downcast_ref::<Dict>().and_then(|dict| dict.get(name)) {
+                    out.push(Match { path: child_path(&node.path, name), value });
+                }
+            },
+            Selector::Wildcard => out.extend(children(node)),
+            Selector::Index(index) => {
+                if let Some(list) = any.downcast_ref::<List>() {
+                    if let Some(index) = normalize_index(*index, list.len()) {
+                        out.push(Match {
+                            path: child_path(&node.path, &index.to_string()),
+                            value: list[index].as_ref()
+                        });
+                    }
+                }
+            },
+            Selector::Slice(start, end, step) => {
+                if let Some(list) = any.downcast_ref::<List>() {
+                    for index in slice_indices(*start, *end, *step, list.len()) {
+                        out.push(Match {
+                            path: child_path(&node.path, &index.to_string()),
+                            value: list[index].as_ref()
+                        });
+                    }
+                }
+            },
+            Selector::Filter(expr) => {
+                for child in children(node) {
+                    if evaluate(expr, child.value, root) {
+                        out.push(child);
+                    }
+                }
+            },
+        }
+    }
+}
+
+fn resolve<'a>(path: &RelativePath, current: &'a dyn Object, root: &'a dyn Object)
+-> Option<&'a dyn Object>
+{
+    let mut obj = if path.from_root { root } else { current };
+    for step in &path.steps {
+        let any = obj.as_any();
+        obj = match step {
+            Selector::Name(name) => any.downcast_ref::<Dict>()?.get(name)?,
+            Selector::Index(index) => {
+                let list = any.downcast_ref::<List>()?;
+                list[normalize_index(*index, list.len())?].as_ref()
+            },
+            _ => return None,
+        };
+    }
+    Some(obj)
+}
+
+fn as_literal(obj: &dyn Object) -> Option<Literal> {
+    let any = obj.as_any();
+    if any.is::<Null>() {
+        Some(Literal::Null)
+    } else if let Some(value) = any.downcast_ref::<Bool>() {
+        Some(Literal::Bool(**value))
+    } else if let Some(value) = any.downcast_ref::<Integer>() {
+        Some(Literal::Number(**value as f64))
+    } else if let Some(value) = any.downcast_ref::<Double>() {
+        Some(Literal::Number(**value))
+    } else {
+        any.downcast_ref::<Text>().map(|value| Literal::Text(value.to_string()))
+    }
+}
+
+// Containers and other types only compare as unequal.
+fn compare(left: Option<Literal>, op: Comparison, right: Option<Literal>) -> bool {
+    let ordering = match (&left, &right) {
+        (Some(Literal::Number(a)), Some(Literal::Number(b))) => a.partial_cmp(b),
+        (Some(Literal::Text(a)), Some(Literal::Text(b))) => Some(a.cmp(b)),
+        _ => None,
+    };
+    let is_equal = left.is_some() && left == right;
+    match op {
+        Comparison::Eq => is_equal,
+        Comparison::Ne => !is_equal,
+        Comparison::Lt => ordering.is_some_and(|ordering| ordering.is_lt()),
+        Comparison::Le => ordering.is_some_and(|ordering| ordering.is_le()),
+        Comparison::Gt => ordering.is_some_and(|ordering| ordering.is_gt()),
+        Comparison::Ge => ordering.is_some_and(|ordering| ordering.is_ge()),
+    }
+}
+
+fn evaluate(expr: &Expr, current: &dyn Object, root: &dyn Object) -> bool {
+    let operand = |operand: &Operand| match operand {
+        Operand::Path(path) => resolve(path, current, root).and_then(as_literal),
+        Operand::Literal(literal) => Some(literal.clone()),
+    };
+    match expr {
+        Expr::Or(left, right) => evaluate(left, current, root) || evaluate(right, current, root),
+        Expr::And(left, right) => evaluate(left, current, root) && evaluate(right, current, root),
+        Expr::Not(inner) => !evaluate(inner, current, root),
+        Expr::Exists(path) => resolve(path, current, root).is_some(),
+        Expr::Compare(left, op, right) => compare(operand(left), *op, operand(right)),
+    }
+}
+
+// Parsing
+
+struct QueryParser<'s> {
+    src: &'s str,
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl QueryParser<'_> {
+    fn error(&self, msg: &str) -> InternalError {
+        let msg = format!("{} at position {} of query \"{}\".", msg, self.pos, self.src);
+        InternalError::new(&msg, ErrorKind::InvalidInput)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn starts_with(&self, pattern: &str) -> bool {
+        pattern.chars().enumerate().all(|(offset, ch)| self.chars.get(self.pos + offset) == Some(&ch))
+    }
+
+    fn eat(&mut self, pattern: &str) -> bool {
+        if self.starts_with(pattern) {
+            self.pos += pattern.chars().count();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, pattern: &str) -> Result<(), InternalError> {
+        if self.eat(pattern) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected '{}'", pattern)))
+        }
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn parse_query(&mut self) -> Result<Query, InternalError> {
+        let mut segments = Vec::new();
+        self.skip_spaces();
+        self.eat("$");
+        while self.peek().is_some() {
+            segments.push(self.parse_segment()?);
+        }
+        Ok(Query { segments })
+    }
+
+    fn parse_segment(&mut self) -> Result<Segment, InternalError> {
+        let recursive = self.eat("..");
+        if !recursive && !self.eat(".") && self.peek() != Some('[') {
+            return Err(self.error("Expected '.', '..' or '['"));
+        }
+        let selectors = if self.peek() == Some('[') {
+            self.parse_bracket()?
+        } else if self.eat("*") {
+            vec![Selector::Wildcard]
+        } else {
+            vec![Selector::Name(self.parse_name()?)]
+        };
+        Ok(Segment { recursive, selectors })
+    }
+
+    fn parse_name(&mut self) -> Result<String, InternalError> {
+        let start = self.pos;
+        while self.peek().is_some_and(|ch| ch.is_alphanumeric() || ch == '_' || ch == '-') {
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return Err(self.error("Expected a name"));
+        }
+        Ok(self.chars[start..self.pos].iter().collect())
+    }
+
+    fn parse_bracket(&mut self) -> Result<Vec<Selector>, InternalError> {
+        self.expect("[")?;
+        let mut selectors = Vec::new();
+        loop {
+            self.skip_spaces();
+            selectors.push(self.parse_selector()?);
+            self.skip_spaces();
+            if self.eat("]") {
+                return Ok(selectors);
+            }
+            self.expect(",")?;
+        }
+    }
+
+    fn parse_selector(&mut self) -> Result<Selector, InternalError> {
+        if self.eat("*") {
+            return Ok(Selector::Wildcard);
+        }
+        if self.eat("?") {
+            self.skip_spaces();
+            return Ok(Selector::Filter(self.parse_or()?));
+        }
+        if matches!(self.peek(), Some('\'') | Some('"')) {
+            return Ok(Selector::Name(self.parse_string()?));
+        }
+        let start = self.parse_optional_integer()?;
+        self.skip_spaces();
+        if !self.eat(":") {
+            return start.map(Selector::Index).ok_or_else(|| self.error("Expected a selector"));
+        }
+        self.skip_spaces();
+        let end = self.parse_optional_integer()?;
+        self.skip_spaces();
+        let mut step = 1;
+        if self.eat(":") {
+            self.skip_spaces();
+            step = self.parse_optional_integer()?.unwrap_or(1);
+        }
+        Ok(Selector::Slice(start, end, step))
+    }
+
+    fn parse_optional_integer(&mut self) -> Result<Option<i64>, InternalError> {
+        let start = self.pos;
+        self.eat("-");
+        while self.peek().is_some_and(|ch| ch.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return Ok(None);
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        text.parse::<i64>().map(Some).map_err(|_| self.error("Invalid integer"))
+    }
+
+    fn parse_string(&mut self) -> Result<String, InternalError> {
+        let quote = self.peek().unwrap();
+        self.pos += 1;
+        let mut out = String::new();
+        loop {
+            match self.peek() {
+                None => return Err(self.error("Unterminated string")),
+                Some(ch) if ch == quote => {
+                    self.pos += 1;
+                    return Ok(out);
+                },
+                Some('\\') => {
+                    self.pos += 1;
+                    match self.peek() {
+                        Some(ch) => out.push(ch),
+                        None => return Err(self.error("Unterminated string")),
+                    }
+                    self.pos += 1;
+                },
+                Some(ch) => {
+                    out.push(ch);
+                    self.pos += 1;
+                },
+            }
+        }
+    }
+
+    // Filter expressions
+
+    fn parse_or(&mut self) -> Result<Expr, InternalError> {
+        let mut expr = self.parse_and()?;
+        loop {
+            self.skip_spaces();
+            if !self.eat("||") {
+                return Ok(expr);
+            }
+            self.skip_spaces();
+            expr = Expr::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, InternalError> {
+        let mut expr = self.parse_unary()?;
+        loop {
+            self.skip_spaces();
+            if !self.eat("&&") {
+                return Ok(expr);
+            }
+            self.skip_spaces();
+            expr = Expr::And(Box::new(expr), Box::new(self.parse_unary()?));
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, InternalError> {
+        if self.starts_with("!") && !self.starts_with("!=") {
+            self.pos += 1;
+            self.skip_spaces();
+            return Ok(Expr::Not(Box::new(self.parse_unary()?)));
+        }
+        if self.eat("(") {
+            self.skip_spaces();
+            let expr = self.parse_or()?;
+            self.skip_spaces();
+            self.expect(")")?;
+            return Ok(expr);
+        }
+        let left = self.parse_operand()?;
+        self.skip_spaces();
+        let op = match self.parse_comparison() {
+            Some(op) => op,
+            None => return match left {
+                Operand::Path(path) => Ok(Expr::Exists(path)),
+                Operand::Literal(_) => Err(self.error("Expected a comparison")),
+            },
+        };
+        self.skip_spaces();
+        let right = self.parse_operand()?;
+        Ok(Expr::Compare(left, op, right))
+    }
+
+    fn parse_comparison(&mut self) -> Option<Comparison> {
+        let operators = [
+            ("==", Comparison::Eq), ("!=", Comparison::Ne),
+            ("<=", Comparison::Le), (">=", Comparison::Ge),
+            ("<", Comparison::Lt), (">", Comparison::Gt),
+        ];
+        operators.iter().find(|(symbol, _)| self.eat(symbol)).map(|(_, op)| *op)
+    }
+
+    fn parse_operand(&mut self) -> Result<Operand, InternalError> {
+        match self.peek() {
+            Some('@') | Some('$') => {
+                let from_root = self.peek() == Some('$');
+                self.pos += 1;
+                Ok(Operand::Path(RelativePath { from_root, steps: self.parse_relative_steps()? }))
+            },
+            Some('\'') | Some('"') => Ok(Operand::Literal(Literal::Text(self.parse_string()?))),
+            _ => {
+                for (word, literal) in [("true", Literal::Bool(true)),
+                                        ("false", Literal::Bool(false)),
+                                        ("null", Literal::Null)] {
+                    if self.eat(word) {
+                        return Ok(Operand::Literal(literal));
+                    }
+                }
+                self.parse_number().map(Operand::Literal)
+            },
+        }
+    }
+
+    fn parse_relative_steps(&mut self) -> Result<Vec<Selector>, InternalError> {
+        let mut steps = Vec::new();
+        loop {
+            if self.starts_with(".") && !self.starts_with("..") {
+                self.pos += 1;
+                steps.push(Selector::Name(self.parse_name()?));
+            } else if self.eat("[") {
+                self.skip_spaces();
+                let step = if matches!(self.peek(), Some('\'') | Some('"')) {
+                    Selector::Name(self.parse_string()?)
+                } else {
+                    let index = self.parse_optional_integer()?;
+                    Selector::Index(index.ok_or_else(|| self.error("Expected a name or an index"))?)
+                };
+                self.skip_spaces();
+                self.expect("]")?;
+                steps.push(step);
+            } else {
+                return Ok(steps);
+            }
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<Literal, InternalError> {
+        let start = self.pos;
+        while self.peek().is_some_and(|ch| ch.is_ascii_digit() || "+-.eE".contains(ch)) {
+            self.pos += 1;
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        match text.parse::<f64>() {
+            Ok(number) if !text.is_empty() => Ok(Literal::Number(number)),
+            _ => {
+                self.pos = start;
+                Err(self.error("Expected a value"))
+            }
+        }
+    }
+}
+
+// Unittest
+#[cfg(test)]
+#[path = "./unittest/query/tests.rs"]
+mod tests;
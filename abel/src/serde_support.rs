@@ -0,0 +1,565 @@
+//! Serde integration, enabled by the `serde` feature.
+//!
+//! Intrinsic types and containers implement `Serialize` and `Deserialize`.
+//! `Complex`, `Binary` and `Bitstring` are strings in their JSON+ form.
+//! `Deserializer` reads any Rust type implementing `Deserialize` from
+//! loaded objects, so JSON+ text can be read with `from_str`.
+
+use std::fmt;
+
+use serde::de::{self, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{self, SerializeMap, SerializeSeq};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::error::{AbelError, Error, ErrorKind, FieldError};
+use crate::object::Object;
+use crate::converter;
+use crate::null::Null;
+use crate::bool::Bool;
+use crate::integer::Integer;
+use crate::double::Double;
+use crate::complex::Complex;
+use crate::binary::Binary;
+use crate::bitstring::Bitstring;
+use crate::text::Text;
+use crate::list::List;
+use crate::dict::Dict;
+use crate::json_loader::{JsonLoader, JsonRoot};
+
+impl ser::Error for FieldError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        FieldError::new(&msg.to_string(), ErrorKind::IncompatibleType)
+    }
+}
+
+impl de::Error for FieldError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        FieldError::new(&msg.to_string(), ErrorKind::MismatchedType)
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        FieldError::new("Key is not found.", ErrorKind::KeyNotFound).with_key(field)
+    }
+}
+
+/// Reads a value from JSON+ text.
+pub fn from_str<T: de::DeserializeOwned>(src: &str) -> Result<T, AbelError> {
+    let mut loader = JsonLoader::new_plus();
+    let root = loader.load_root_from_str(src)?;
+    Ok(from_root(&root)?)
+}
+
+/// Reads a value from a JSON+ file.
+pub fn from_file<T: de::DeserializeOwned>(filename: &str) -> Result<T, AbelError> {
+    let mut loader = JsonLoader::new_plus();
+    let root = loader.load_root_from_file(filename)?;
+    Ok(from_root(&root)?)
+}
+
+pub fn from_root<'a, T: Deserialize<'a>>(root: &'a JsonRoot) -> Result<T, FieldError> {
+    match root {
+        JsonRoot::Dict(dict) => from_object(dict),
+        JsonRoot::List(list) => from_object(list),
+        JsonRoot::Scalar(scalar) => from_object(scalar.as_ref()),
+    }
+}
+
+pub fn from_object<'a, T: Deserialize<'a>>(obj: &'a dyn Object) -> Result<T, FieldError> {
+    T::deserialize(Deserializer::new(obj))
+}
+
+// Serialization
+
+/// Any supported object behind a reference.
+struct ObjectRef<'a>(&'a dyn Object);
+
+impl Serialize for ObjectRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let any = self.0.as_any();
+        if any.is::<Null>() {
+            serializer.serialize_unit()
+        } else if let Some(value) = any.downcast_ref::<Bool>() {
+            value.serialize(serializer)
+        } else if let Some(value) = any.downcast_ref::<Integer>() {
+            value.serialize(serializer)
+        } else if let Some(value) = any.downcast_ref::<Double>() {
+            value.serialize(serializer)
+        } else if let Some(value) = any.downcast_ref::<Complex>() {
+            value.serialize(serializer)
+        } else if let Some(value) = any.downcast_ref::<Binary>() {
+            value.serialize(serializer)
+        } else if let Some(value) = any.downcast_ref::<Bitstring>() {
+            value.serialize(serializer)
+        } else if let Some(value) = any.downcast_ref::<Text>() {
+            value.serialize(serializer)
+        } else if let Some(value) = any.downcast_ref::<List>() {
+            value.serialize(serializer)
+        } else if let Some(value) = any.downcast_ref::<Dict>() {
+            value.serialize(serializer)
+        } else {
+            let msg = format!("Type {} is not serializable.", self.0.type_name());
+            Err(ser::Error::custom(msg))
+        }
+    }
+}
+
+impl Serialize for Null {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit()
+    }
+}
+
+impl Serialize for Bool {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bool(**self)
+    }
+}
+
+impl Serialize for Integer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(**self)
+    }
+}
+
+impl Serialize for Double {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(**self)
+    }
+}
+
+impl Serialize for Text {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+/// Same form as the JSON+ writer, e.g. `1.0+2.0j`. Like the writer, fails
+/// on NaN and infinite parts, which can't be read back.
+impl Serialize for Complex {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !(self.real().is_finite() && self.imag().is_finite()) {
+            let msg = format!("Complex {} cannot be serialized.", self);
+            return Err(ser::Error::custom(msg));
+        }
+        serializer.serialize_str(&self.to_json_plus())
+    }
+}
+
+impl Serialize for Binary {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+impl Serialize for Bitstring {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+impl Serialize for List {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for element in self.iter() {
+            seq.serialize_element(&ObjectRef(element.as_ref()))?;
+        }
+        seq.end()
+    }
+}
+
+impl Serialize for Dict {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for (key, value) in self.iter() {
+            map.serialize_entry(key, &ObjectRef(value.as_ref()))?;
+        }
+        map.end()
+    }
+}
+
+// Deserialization of intrinsic types
+
+impl<'de> Deserialize<'de> for Null {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <()>::deserialize(deserializer).map(|_| Null::new())
+    }
+}
+
+impl<'de> Deserialize<'de> for Bool {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        bool::deserialize(deserializer).map(Bool::new)
+    }
+}
+
+impl<'de> Deserialize<'de> for Integer {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        i32::deserialize(deserializer).map(Integer::new)
+    }
+}
+
+impl<'de> Deserialize<'de> for Double {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        f64::deserialize(deserializer).map(Double::new)
+    }
+}
+
+impl<'de> Deserialize<'de> for Text {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(|text| Text::new(&text))
+    }
+}
+
+impl<'de> Deserialize<'de> for Complex {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        converter::as_complex(&text).map_err(|error| de::Error::custom(error.get_msg()))
+    }
+}
+
+impl<'de> Deserialize<'de> for Binary {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        converter::as_binary(&text).map_err(|error| de::Error::custom(error.get_msg()))
+    }
+}
+
+impl<'de> Deserialize<'de> for Bitstring {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        converter::as_bitstring(&text).map_err(|error| de::Error::custom(error.get_msg()))
+    }
+}
+
+// Deserialization of containers, whose values keep the type given by the
+// format. Integers that don't fit into `Integer` become doubles.
+
+struct ObjectVisitor;
+
+impl<'de> Visitor<'de> for ObjectVisitor {
+    type Value = Box<dyn Object>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "any value")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Box::new(Null::new()))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Box::new(Null::new()))
+    }
+
+    fn visit_some<D: de::Deserializer<'de>>(self, deserializer: D)
+    -> Result<Self::Value, D::Error>
+    {
+        deserializer.deserialize_any(ObjectVisitor)
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(Box::new(Bool::new(value)))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        match i32::try_from(value) {
+            Ok(value) => Ok(Box::new(Integer::new(value))),
+            Err(_) => Ok(Box::new(Double::new(value as f64))),
+        }
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        match i32::try_from(value) {
+            Ok(value) => Ok(Box::new(Integer::new(value))),
+            Err(_) => Ok(Box::new(Double::new(value as f64))),
+        }
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(Box::new(Double::new(value)))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(Box::new(Text::new(value)))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut list = List::new();
+        while let Some(element) = seq.next_element_seed(BoxedObject)? {
+            list.push_box(element);
+        }
+        Ok(Box::new(list))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut dict = Dict::new();
+        while let Some(key) = map.next_key::<String>()? {
+            let value = map.next_value_seed(BoxedObject)?;
+            dict.insert_box(&key, value);
+        }
+        Ok(Box::new(dict))
+    }
+}
+
+struct BoxedObject;
+
+impl<'de> DeserializeSeed<'de> for BoxedObject {
+    type Value = Box<dyn Object>;
+
+    fn deserialize<D: de::Deserializer<'de>>(self, deserializer: D)
+    -> Result<Self::Value, D::Error>
+    {
+        deserializer.deserialize_any(ObjectVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for List {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut obj = deserializer.deserialize_seq(ObjectVisitor)?;
+        match obj.as_mut_any().downcast_mut::<List>() {
+            Some(list) => Ok(std::mem::take(list)),
+            None => Err(de::Error::custom("expected List")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Dict {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut obj = deserializer.deserialize_map(ObjectVisitor)?;
+        match obj.as_mut_any().downcast_mut::<Dict>() {
+            Some(dict) => Ok(std::mem::take(dict)),
+            None => Err(de::Error::custom("expected Dict")),
+        }
+    }
+}
+
+// Deserializer
+
+/// Deserializer reading from a loaded object. Errors carry the path of the
+/// failing value, as those of `FromAbel`.
+pub struct Deserializer<'a> {
+    obj: &'a dyn Object,
+}
+
+impl<'a> Deserializer<'a> {
+    pub fn new(obj: &'a dyn Object) -> Self {
+        Deserializer {
+            obj
+        }
+    }
+
+    // Integral doubles are accepted where integers are expected, since
+    // standard JSON has no integers.
+    fn integral(&self) -> Option<i64> {
+        let any = self.obj.as_any();
+        if let Some(integer) = any.downcast_ref::<Integer>() {
+            return Some(**integer as i64);
+        }
+        match any.downcast_ref::<Double>() {
+            Some(double) if double.fract() == 0.0
+                    && double.abs() < i64::MAX as f64 => Some(**double as i64),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! deserialize_integer {
+    ($($method:ident),*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FieldError> {
+                match self.integral() {
+                    Some(value) => visitor.visit_i64(value),
+                    None => self.deserialize_any(visitor),
+                }
+            }
+        )*
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer<'de> {
+    type Error = FieldError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FieldError> {
+        let any = self.obj.as_any();
+        if any.is::<Null>() {
+            visitor.visit_unit()
+        } else if let Some(value) = any.downcast_ref::<Bool>() {
+            visitor.visit_bool(**value)
+        } else if let Some(value) = any.downcast_ref::<Integer>() {
+            visitor.visit_i32(**value)
+        } else if let Some(value) = any.downcast_ref::<Double>() {
+            visitor.visit_f64(**value)
+        } else if let Some(value) = any.downcast_ref::<Text>() {
+            visitor.visit_borrowed_str(value.as_str())
+        } else if let Some(value) = any.downcast_ref::<Binary>() {
+            visitor.visit_borrowed_str(value.as_str())
+        } else if let Some(value) = any.downcast_ref::<Bitstring>() {
+            visitor.visit_borrowed_str(value.as_str())
+        } else if let Some(value) = any.downcast_ref::<Complex>() {
+            visitor.visit_string(value.to_json_plus())
+        } else if let Some(list) = any.downcast_ref::<List>() {
+            visitor.visit_seq(ListAccess { list, index: 0 })
+        } else if let Some(dict) = any.downcast_ref::<Dict>() {
+            visitor.visit_map(DictAccess { iter: dict.iter(), key: None })
+        } else {
+            let msg = format!("Type {} is not deserializable.", self.obj.type_name());
+            Err(FieldError::new(&msg, ErrorKind::IncompatibleType))
+        }
+    }
+
+    deserialize_integer!(deserialize_i8, deserialize_i16, deserialize_i32, deserialize_i64,
+                         deserialize_u8, deserialize_u16, deserialize_u32, deserialize_u64);
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FieldError> {
+        if self.obj.as_any().is::<Null>() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V)
+    -> Result<V::Value, FieldError>
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// Unit variants are texts, other variants dictionaries with the
+    /// variant name as their only key.
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V
+    ) -> Result<V::Value, FieldError> {
+        let any = self.obj.as_any();
+        if let Some(text) = any.downcast_ref::<Text>() {
+            return visitor.visit_enum(text.as_str().into_deserializer());
+        }
+        match any.downcast_ref::<Dict>() {
+            Some(dict) if dict.len() == 1 => {
+                let (variant, value) = dict.iter().next().unwrap();
+                visitor.visit_enum(EnumAccess { variant, value: value.as_ref() })
+            },
+            _ => {
+                let msg = format!("expected enum, found {}", self.obj.type_name());
+                Err(FieldError::new(&msg, ErrorKind::MismatchedType))
+            },
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        i128 u128 f32 f64 bool char str string bytes byte_buf unit unit_struct
+        seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct ListAccess<'a> {
+    list: &'a List,
+    index: usize,
+}
+
+impl<'a> SeqAccess<'a> for ListAccess<'a> {
+    type Error = FieldError;
+
+    fn next_element_seed<T: DeserializeSeed<'a>>(&mut self, seed: T)
+    -> Result<Option<T::Value>, FieldError>
+    {
+        if self.index >= self.list.len() {
+            return Ok(None);
+        }
+        let index = self.index;
+        self.index += 1;
+        seed.deserialize(Deserializer::new(self.list[index].as_ref()))
+            .map(Some)
+            .map_err(|error| error.with_index(index))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.list.len() - self.index)
+    }
+}
+
+struct DictAccess<'a> {
+    iter: crate::dict::Iter<'a>,
+    key: Option<(&'a String, &'a dyn Object)>,
+}
+
+impl<'a> MapAccess<'a> for DictAccess<'a> {
+    type Error = FieldError;
+
+    fn next_key_seed<K: DeserializeSeed<'a>>(&mut self, seed: K)
+    -> Result<Option<K::Value>, FieldError>
+    {
+        match self.iter.next() {
+            Some((key, value)) => {
+                self.key = Some((key, value.as_ref()));
+                seed.deserialize(key.as_str().into_deserializer()).map(Some)
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'a>>(&mut self, seed: V)
+    -> Result<V::Value, FieldError>
+    {
+        let (key, value) = self.key.take()
+            .ok_or_else(|| FieldError::new("Value without a key.", ErrorKind::InvalidInput))?;
+        seed.deserialize(Deserializer::new(value)).map_err(|error| error.with_key(key))
+    }
+}
+
+struct EnumAccess<'a> {
+    variant: &'a String,
+    value: &'a dyn Object,
+}
+
+impl<'a> de::EnumAccess<'a> for EnumAccess<'a> {
+    type Error = FieldError;
+    type Variant = VariantAccess<'a>;
+
+    fn variant_seed<V: DeserializeSeed<'a>>(self, seed: V)
+    -> Result<(V::Value, VariantAccess<'a>), FieldError>
+    {
+        let variant = seed.deserialize(self.variant.as_str().into_deserializer())?;
+        Ok((variant, VariantAccess { variant: self.variant, value: self.value }))
+    }
+}
+
+struct VariantAccess<'a> {
+    variant: &'a String,
+    value: &'a dyn Object,
+}
+
+impl<'a> de::VariantAccess<'a> for VariantAccess<'a> {
+    type Error = FieldError;
+
+    fn unit_variant(self) -> Result<(), FieldError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'a>>(self, seed: T)
+    -> Result<T::Value, FieldError>
+    {
+        seed.deserialize(Deserializer::new(self.value))
+            .map_err(|error| error.with_key(self.variant))
+    }
+
+    fn tuple_variant<V: Visitor<'a>>(self, _len: usize, visitor: V)
+    -> Result<V::Value, FieldError>
+    {
+        de::Deserializer::deserialize_seq(Deserializer::new(self.value), visitor)
+            .map_err(|error| error.with_key(self.variant))
+    }
+
+    fn struct_variant<V: Visitor<'a>>(self, _fields: &'static [&'static str], visitor: V)
+    -> Result<V::Value, FieldError>
+    {
+        de::Deserializer::deserialize_map(Deserializer::new(self.value), visitor)
+            .map_err(|error| error.with_key(self.variant))
+    }
+}
+
+// Unittest
+#[cfg(test)]
+#[path = "./unittest/serde_support/tests.rs"]
+mod tests;
@@ -0,0 +1,67 @@
+use std::fmt;
+
+/// Location of a character in source text. Lines start at 1, columns at 0
+/// and count characters, the offset counts bytes from the start of input.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Position {
+    pub line: i32,
+    pub column: i32,
+    pub offset: usize,
+}
+
+impl Position {
+    pub fn new(line: i32, column: i32, offset: usize) -> Self {
+        Position { line, column, offset }
+    }
+
+    /// Position right after the given character at this position.
+    pub fn after(&self, ch: char) -> Self {
+        Position {
+            line: self.line,
+            column: self.column + 1,
+            offset: self.offset + ch.len_utf8(),
+        }
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Range of source text, the end is exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Span {
+    pub fn new(start: Position, end: Position) -> Self {
+        Span { start, end }
+    }
+
+    /// Span of a single character.
+    pub fn of_char(start: Position, ch: char) -> Self {
+        Span { start, end: start.after(ch) }
+    }
+
+    pub fn len(&self) -> usize {
+        self.end.offset - self.start.offset
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+#[path = "./unittest/span/tests.rs"]
+mod tests;
@@ -38,8 +38,8 @@ pub const L_CHEVRON: char = '<';
 pub const R_CHEVRON: char = '>';
 
 /// Container symbols
-pub const CONTAINER_TYPES: [&'static str; 3] = [ "Dict", "List", "Tuple" ];
-pub const ITERABLE_CONTAINERS: [&'static str; 2]  = [ "List", "Tuple" ];
+pub const CONTAINER_TYPES: [&str; 3] = [ "Dict", "List", "Tuple" ];
+pub const ITERABLE_CONTAINERS: [&str; 2]  = [ "List", "Tuple" ];
 pub const CONTAINER_OPENING_SYMBOLS: [char; 3] = [
     L_BRACE, L_BRACKET, L_PARENTHESIS
 ];
@@ -47,9 +47,9 @@ pub const CONTAINER_OPENING_SYMBOLS: [char; 3] = [
 pub const CONTAINER_CLOSING_SYMBOLS: [char; 3] = [
     R_BRACE, R_BRACKET, R_PARENTHESIS
 ];
-pub const CONTAINER_OPENING_TOKEN_TYPES: [&'static str; 3] = [
+pub const CONTAINER_OPENING_TOKEN_TYPES: [&str; 3] = [
     "DictOpening", "ListOpening", "TupleOpening"
 ];
-pub const CONTAINER_CLOSING_TOKEN_TYPES: [&'static str; 3] = [
+pub const CONTAINER_CLOSING_TOKEN_TYPES: [&str; 3] = [
     "DictClosing", "ListClosing", "TupleClosing"
 ];
\ No newline at end of file
@@ -2,11 +2,13 @@ use std::fmt;
 use std::any::Any;
 use std::ops::Deref;
 
+use crate::error::InternalError;
 use crate::object::Object;
 use crate::marker::ScalarValued;
 use crate::typefy::IntoType;
+use crate::json_writer::escape_string;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Text {
     internal: String,
 }
@@ -37,6 +39,18 @@ impl Object for Text {
     fn type_name(&self) -> &'static str {
         "Text"
     }
+
+    fn clone_box(&self) -> Result<Box<dyn Object>, InternalError> {
+        Ok(Box::new(self.clone()))
+    }
+
+    fn fmt_display(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "\"{}\"", escape_string(&self.internal))
+    }
+
+    fn fmt_debug(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
 }
 
 impl ScalarValued<String> for Text {
@@ -7,6 +7,8 @@ pub trait NamedType {
 pub trait IntoType {
     type TargetType: Object;
 
+    // Primitives are converted by copy, so `self` is taken by reference.
+    #[allow(clippy::wrong_self_convention)]
     fn into_type(&self) -> Self::TargetType;
 }
 
@@ -16,13 +16,13 @@ mod test_bool {
     #[test]
     fn test_constructor_from_value() {
         let rabel_bool = Bool::new(false);
-        assert_eq!( false, rabel_bool.value() );
+        assert!( !rabel_bool.value() );
     }
 
     #[test]
     fn test_from_string() {
-        assert_eq!(false, Bool::from_str("false").unwrap().value());
-        assert_eq!(true, Bool::from_str("true").unwrap().value());
+        assert!(!Bool::from_str("false").unwrap().value());
+        assert!(Bool::from_str("true").unwrap().value());
         assert_eq!(Bool::from_str("TRUE").unwrap_err().get_header(),
                    "FAILED_TO_IDENTIFY");
     }
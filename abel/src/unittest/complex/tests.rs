@@ -42,7 +42,7 @@ mod test_complex_partial_eq {
         // Integer == Complex
         assert!(!(test_int == test_complex));
         // i32 == Complex
-        assert!(!(100 == test_complex));
+        assert!((100 != test_complex));
     }
 }
 
@@ -78,4 +78,15 @@ mod test_complex_verifier {
         assert!(!cimag.is_zero());
         assert!(czero.is_zero());
     }
-}
\ No newline at end of file
+}
+mod test_complex_json_plus {
+    use super::*;
+
+    #[test]
+    fn test_to_json_plus() {
+        assert_eq!(Complex::new(1.0, 2.0).to_json_plus(), "1.0+2.0j");
+        assert_eq!(Complex::new(-0.5, -3.0).to_json_plus(), "-0.5-3.0j");
+        let object: &dyn crate::object::Object = &Complex::new(1.0, 2.0);
+        assert_eq!(object.to_string(), "1.0+2.0j");
+    }
+}
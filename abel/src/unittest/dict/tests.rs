@@ -249,31 +249,22 @@ mod test_dict_container_trait_methods {
         match test_dict.get("k1") {
             Some(ref_to_box) => {
                 assert!(ref_to_box.as_any().is::<Integer>());
-                match ref_to_box.as_any().downcast_ref::<Integer>() {
-                    Some(ref_to_type) => {
-                        assert_eq!(ref_to_type.value(), 10);
-                    },
-                    _ => {},
+                if let Some(ref_to_type) = ref_to_box.as_any().downcast_ref::<Integer>() {
+                    assert_eq!(ref_to_type.value(), 10);
                 }
             },
             _ => println!("Not integer") 
         }
 
         // Access a List inside the dictionary
-        match test_dict.get("k2") {
-            Some(ref_to_box) => {
-                assert!(ref_to_box.as_any().is::<List>());
-                match ref_to_box.as_any().downcast_ref::<List>() {
-                    Some(ref_to_type) => {
-                        // list has one item
-                        assert_eq!(ref_to_type.len(), 1);
-                        // only item in list is a Rstring
-                        assert!(ref_to_type[0].as_any().is::<Text>());
-                    },
-                    _ => {}
-                }
-            },
-            _ => {}
+        if let Some(ref_to_box) = test_dict.get("k2") {
+            assert!(ref_to_box.as_any().is::<List>());
+            if let Some(ref_to_type) = ref_to_box.as_any().downcast_ref::<List>() {
+                // list has one item
+                assert_eq!(ref_to_type.len(), 1);
+                // only item in list is a Rstring
+                assert!(ref_to_type[0].as_any().is::<Text>());
+            }
         }
     }
 
@@ -346,4 +337,294 @@ mod test_dict_container_trait_methods {
         // since it is a mutable borrow, let's insert something.
         dict.insert("int2", Integer::new(0));
     }
-}
\ No newline at end of file
+}
+
+mod test_dict_insertion_order {
+    use super::*;
+    use crate::integer::Integer;
+    use crate::text::Text;
+
+    fn keys_of(dict: &Dict) -> Vec<String> {
+        dict.entries().into_iter().map(|(key, _)| key.clone()).collect()
+    }
+
+    #[test]
+    fn test_order_of_keys() {
+        let mut dict = Dict::new();
+        dict.insert("zeta", Integer::new(1));
+        dict.insert("alpha", Integer::new(2));
+        dict.set("mid", Text::new("3")).unwrap();
+        dict.insert_from("beta", 4);
+        assert_eq!(keys_of(&dict), vec!["zeta", "alpha", "mid", "beta"]);
+    }
+
+    #[test]
+    fn test_replacing_keeps_position() {
+        let mut dict = Dict::new();
+        dict.insert("k1", Integer::new(1));
+        dict.insert("k2", Integer::new(2));
+        dict.insert("k1", Text::new("replaced"));
+        assert_eq!(keys_of(&dict), vec!["k1", "k2"]);
+        assert_eq!(dict.get_ref::<Text>("k1").unwrap(), "replaced");
+    }
+}
+
+mod test_dict_iteration {
+    use super::*;
+    use crate::integer::Integer;
+    use crate::text::Text;
+    use crate::typefy::Downcast;
+
+    fn make_dict() -> Dict {
+        let mut dict = Dict::new();
+        dict.insert("k1", Integer::new(1));
+        dict.insert("k2", Text::new("two"));
+        dict.insert("k3", Integer::new(3));
+        dict
+    }
+
+    #[test]
+    fn test_keys_and_values() {
+        let dict = make_dict();
+        assert_eq!(dict.keys().collect::<Vec<&String>>(), vec!["k1", "k2", "k3"]);
+        let type_names: Vec<&str> = dict.values().map(|value| value.type_name()).collect();
+        assert_eq!(type_names, vec!["Integer", "Text", "Integer"]);
+    }
+
+    #[test]
+    fn test_iter() {
+        let dict = make_dict();
+        let mut total = 0;
+        for (key, value) in &dict {
+            if let Some(integer) = value.to_ref::<Integer>() {
+                total += **integer;
+            } else {
+                assert_eq!(key, "k2");
+            }
+        }
+        assert_eq!(total, 4);
+        assert_eq!(dict.iter().count(), 3);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut dict = make_dict();
+        for (_, value) in dict.iter_mut() {
+            if value.type_name() == "Integer" {
+                *value = Box::new(Integer::new(0));
+            }
+        }
+        assert_eq!(*dict.get_ref::<Integer>("k1").unwrap(), 0);
+        assert_eq!(*dict.get_ref::<Integer>("k3").unwrap(), 0);
+        assert_eq!(dict.get_ref::<Text>("k2").unwrap(), "two");
+    }
+
+    #[test]
+    fn test_from_iter_and_into_iter() {
+        let pairs: Vec<(String, Box<dyn Object>)> = vec![
+            ("b".to_string(), Box::new(Integer::new(1))),
+            ("a".to_string(), Box::new(Integer::new(2))),
+            ("b".to_string(), Box::new(Integer::new(3))),
+        ];
+        let dict: Dict = pairs.into_iter().collect();
+        assert_eq!(dict.keys().collect::<Vec<&String>>(), vec!["b", "a"]);
+        assert_eq!(*dict.get_ref::<Integer>("b").unwrap(), 3);
+
+        let keys: Vec<String> = dict.into_iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, vec!["b", "a"]);
+    }
+}
+
+mod test_dict_removal {
+    use super::*;
+    use crate::integer::Integer;
+    use crate::text::Text;
+
+    fn make_dict() -> Dict {
+        let mut dict = Dict::new();
+        dict.insert("k1", Integer::new(1));
+        dict.insert("k2", Text::new("two"));
+        dict.insert("k3", Integer::new(3));
+        dict
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut dict = make_dict();
+        let removed = dict.remove("k2").unwrap();
+        assert_eq!(removed.type_name(), "Text");
+        assert_eq!(dict.len(), 2);
+        assert!(!dict.has_key("k2"));
+        // Remaining keys are still reachable and in order
+        assert_eq!(*dict.get_ref::<Integer>("k3").unwrap(), 3);
+        assert_eq!(dict.keys().collect::<Vec<&String>>(), vec!["k1", "k3"]);
+        // Re-inserted key goes to the end
+        dict.insert("k2", Integer::new(2));
+        assert_eq!(dict.keys().collect::<Vec<&String>>(), vec!["k1", "k3", "k2"]);
+    }
+
+    #[test]
+    fn test_remove_from_front() {
+        let mut dict = make_dict();
+        dict.remove("k1").unwrap();
+        assert_eq!(dict.get_ref::<Text>("k2").unwrap(), "two");
+        dict.remove("k2").unwrap();
+        assert_eq!(*dict.get_ref::<Integer>("k3").unwrap(), 3);
+        dict.remove("k3").unwrap();
+        assert!(dict.is_empty());
+    }
+
+    #[test]
+    fn test_remove_missing_key() {
+        let mut dict = make_dict();
+        let error = dict.remove("k4").err().unwrap();
+        assert_eq!(error.get_header(), "KEY_NOT_FOUND");
+        assert_eq!(dict.len(), 3);
+    }
+
+    #[test]
+    fn test_pop_and_clear() {
+        let mut dict = make_dict();
+        let (key, value) = dict.pop().unwrap();
+        assert_eq!(key, "k3");
+        assert_eq!(value.type_name(), "Integer");
+        assert!(!dict.has_key("k3"));
+        dict.clear();
+        assert!(dict.is_empty());
+        assert!(!dict.has_key("k1"));
+        assert_eq!(dict.pop().err().unwrap().get_header(), "KEY_NOT_FOUND");
+    }
+}
+
+mod test_dict_clone {
+    use super::*;
+    use crate::integer::Integer;
+    use crate::complex::Complex;
+    use crate::text::Text;
+    use crate::list::List;
+
+    // User type without cloning support
+    struct Opaque;
+
+    impl Object for Opaque {
+        fn as_any(&self) -> &dyn Any { self }
+        fn as_mut_any(&mut self) -> &mut dyn Any { self }
+        fn type_name(&self) -> &'static str { "Opaque" }
+    }
+
+    // User type opting in cloning
+    #[derive(Clone)]
+    struct Point {
+        x: i32,
+    }
+
+    impl Object for Point {
+        fn as_any(&self) -> &dyn Any { self }
+        fn as_mut_any(&mut self) -> &mut dyn Any { self }
+        fn type_name(&self) -> &'static str { "Point" }
+        fn clone_box(&self) -> Result<Box<dyn Object>, InternalError> {
+            Ok(Box::new(self.clone()))
+        }
+    }
+
+    #[test]
+    fn test_deep_clone() {
+        let mut subdict = Dict::new();
+        subdict.insert("CPL", Complex::new(1.0, 2.0));
+        let mut dict = Dict::new();
+        dict.insert("INT", Integer::new(1));
+        dict.insert("LIST", List::from_slice(&["a", "b"]));
+        dict.insert("DICT", subdict);
+
+        let mut copy = dict.try_clone().unwrap();
+        // Modifying the copy leaves the original intact
+        *copy.get_mut_ref::<Integer>("INT").unwrap() = Integer::new(10);
+        copy.get_mut_ref::<Dict>("DICT").unwrap().insert("NEW", Text::new("x"));
+        assert_eq!(*dict.get_ref::<Integer>("INT").unwrap(), 1);
+        assert!(!dict.get_ref::<Dict>("DICT").unwrap().has_key("NEW"));
+        assert_eq!(*copy.get_ref::<Integer>("INT").unwrap(), 10);
+        assert_eq!(copy.get_ref::<List>("LIST").unwrap().get_ref::<Text>(1).unwrap(), "b");
+        assert_eq!(
+            *copy.get_ref::<Dict>("DICT").unwrap().get_ref::<Complex>("CPL").unwrap(),
+            Complex::new(1.0, 2.0));
+        assert_eq!(copy.keys().collect::<Vec<&String>>(), vec!["INT", "LIST", "DICT"]);
+    }
+
+    #[test]
+    fn test_clone_user_types() {
+        let mut dict = Dict::new();
+        dict.insert("POINT", Point { x: 3 });
+        let copy = dict.try_clone().unwrap();
+        assert_eq!(copy.get_ref::<Point>("POINT").unwrap().x, 3);
+
+        let boxed: Box<dyn Object> = Box::new(Point { x: 4 });
+        let boxed_copy = boxed.clone_box().unwrap();
+        assert_eq!(boxed_copy.as_any().downcast_ref::<Point>().unwrap().x, 4);
+
+        dict.insert("OPAQUE", Opaque);
+        let error = dict.try_clone().err().unwrap();
+        assert_eq!(error.get_header(), "INCOMPATIBLE_TYPE");
+    }
+}
+
+mod test_dict_format {
+    use super::*;
+    use crate::integer::Integer;
+    use crate::double::Double;
+    use crate::complex::Complex;
+    use crate::null::Null;
+    use crate::text::Text;
+    use crate::list::List;
+
+    struct Opaque;
+
+    impl Object for Opaque {
+        fn as_any(&self) -> &dyn Any { self }
+        fn as_mut_any(&mut self) -> &mut dyn Any { self }
+        fn type_name(&self) -> &'static str { "Opaque" }
+    }
+
+    fn make_dict() -> Dict {
+        let mut dict = Dict::new();
+        dict.insert("STR", Text::new("say \"hi\""));
+        dict.insert("DBL", Double::new(5.0));
+        dict.insert("CPL", Complex::new(0.1, -2.0));
+        dict.insert("LIST", List::from_slice(&[1, 2]));
+        dict.insert("NULL", Null::new());
+        dict
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Dict::new()), "{}");
+        assert_eq!(
+            format!("{}", make_dict()),
+            "{\"STR\": \"say \\\"hi\\\"\", \"DBL\": 5.0, \"CPL\": 0.1-2.0j, \
+             \"LIST\": [1, 2], \"NULL\": null}"
+        );
+        let boxed: Box<dyn Object> = Box::new(make_dict());
+        assert_eq!(boxed.to_string(), make_dict().to_string());
+    }
+
+    #[test]
+    fn test_debug() {
+        let mut dict = Dict::new();
+        dict.insert("INT", Integer::new(5));
+        dict.insert("LIST", List::from_slice(&["a"]));
+        assert_eq!(
+            format!("{:?}", dict),
+            "Dict {\"INT\": Integer { internal: 5 }, \
+             \"LIST\": List [Text { internal: \"a\" }]}"
+        );
+        // Pretty debug output spans multiple lines
+        assert!(format!("{:#?}", dict).contains('\n'));
+    }
+
+    #[test]
+    fn test_user_type() {
+        let mut dict = Dict::new();
+        dict.insert("OPAQUE", Opaque);
+        assert_eq!(format!("{}", dict), "{\"OPAQUE\": <Opaque>}");
+        assert_eq!(format!("{:?}", dict), "Dict {\"OPAQUE\": Opaque}");
+    }
+}
@@ -0,0 +1,147 @@
+//! Unittest crate equality
+//! Crate location: /src/equality.rs
+use super::*;
+use std::any::Any;
+use std::hash::Hash;
+use crate::container::Container;
+
+fn make_tree() -> Dict {
+    let mut list = List::new();
+    list.push(Integer::new(1));
+    list.push(Text::new("R2D2"));
+    list.push(Null::new());
+    let mut dict = Dict::new();
+    dict.insert("BOOL", Bool::new(true));
+    dict.insert("CPL", Complex::new(0.1, 2.0));
+    dict.insert("BIN", Binary::new("0b101"));
+    dict.insert("BIS", Bitstring::new("_b1.01"));
+    dict.insert("LIST", list);
+    dict
+}
+
+// User type taking part in comparison
+struct Point {
+    x: i32,
+}
+
+impl Object for Point {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_mut_any(&mut self) -> &mut dyn Any { self }
+    fn type_name(&self) -> &'static str { "Point" }
+    fn equals(&self, other: &dyn Object) -> bool {
+        match other.as_any().downcast_ref::<Point>() {
+            Some(point) => point.x == self.x,
+            None => false,
+        }
+    }
+    fn hash_into(&self, state: &mut dyn Hasher) {
+        state.write(&self.x.to_le_bytes());
+    }
+}
+
+mod test_deep_eq {
+    use super::*;
+
+    #[test]
+    fn test_equal_trees() {
+        assert!(deep_eq(&make_tree(), &make_tree()));
+        assert!(make_tree() == make_tree());
+        let boxed: Box<dyn Object> = Box::new(make_tree());
+        let other: Box<dyn Object> = Box::new(make_tree());
+        assert!(boxed == other);
+    }
+
+    #[test]
+    fn test_key_order_is_irrelevant() {
+        let mut first = Dict::new();
+        first.insert("a", Integer::new(1));
+        first.insert("b", Integer::new(2));
+        let mut second = Dict::new();
+        second.insert("b", Integer::new(2));
+        second.insert("a", Integer::new(1));
+        assert!(first == second);
+    }
+
+    #[test]
+    fn test_unequal_trees() {
+        let mut other = make_tree();
+        other.get_mut_ref::<List>("LIST").unwrap().push(Null::new());
+        assert!(make_tree() != other);
+
+        let mut other = make_tree();
+        other.insert("BOOL", Bool::new(false));
+        assert!(make_tree() != other);
+
+        let mut other = make_tree();
+        other.insert("EXTRA", Null::new());
+        assert!(make_tree() != other);
+
+        // Same content, different types
+        assert!(!deep_eq(&Text::new("0b101"), &Binary::new("0b101")));
+        assert!(!deep_eq(&Dict::new(), &List::new()));
+    }
+
+    #[test]
+    fn test_numeric_policy() {
+        let integer = Integer::new(5);
+        let double = Double::new(5.0);
+        let complex = Complex::new(5.0, 0.0);
+        assert!(deep_eq(&integer, &double));
+        assert!(deep_eq(&double, &complex));
+        assert!(!deep_eq_with(&integer, &double, NumericEquality::Strict));
+        assert!(deep_eq_with(&integer, &Integer::new(5), NumericEquality::Strict));
+
+        let loaded = List::from_slice(&[1.0, 2.0]);
+        let expected = List::from_slice(&[1, 2]);
+        assert!(loaded == expected);
+        assert!(!deep_eq_with(&loaded, &expected, NumericEquality::Strict));
+    }
+
+    #[test]
+    fn test_user_type() {
+        let mut first = Dict::new();
+        first.insert("P", Point { x: 1 });
+        let mut second = Dict::new();
+        second.insert("P", Point { x: 1 });
+        assert!(first == second);
+        second.insert("P", Point { x: 2 });
+        assert!(first != second);
+    }
+}
+
+mod test_deep_hash {
+    use super::*;
+
+    #[test]
+    fn test_stable_hash() {
+        assert_eq!(stable_hash(&make_tree()), stable_hash(&make_tree()));
+        assert_ne!(stable_hash(&make_tree()), stable_hash(&Dict::new()));
+        assert_ne!(stable_hash(&Text::new("a")), stable_hash(&Text::new("b")));
+        // Known value guards against accidental changes of the algorithm
+        assert_eq!(stable_hash(&Null::new()), 0xea774b00a3561841);
+    }
+
+    #[test]
+    fn test_hash_agrees_with_equality() {
+        assert_eq!(stable_hash(&Integer::new(5)), stable_hash(&Double::new(5.0)));
+        assert_eq!(stable_hash(&Double::new(0.0)), stable_hash(&Double::new(-0.0)));
+        let mut first = Dict::new();
+        first.insert("a", Integer::new(1));
+        first.insert("b", Point { x: 2 });
+        let mut second = Dict::new();
+        second.insert("b", Point { x: 2 });
+        second.insert("a", Double::new(1.0));
+        assert_eq!(stable_hash(&first), stable_hash(&second));
+    }
+
+    #[test]
+    fn test_hash_trait() {
+        let mut state = StableHasher::new();
+        make_tree().hash(&mut state);
+        assert_eq!(state.finish(), stable_hash(&make_tree()));
+        let boxed: Box<dyn Object> = Box::new(make_tree());
+        let mut state = StableHasher::new();
+        boxed.hash(&mut state);
+        assert_eq!(state.finish(), stable_hash(&make_tree()));
+    }
+}
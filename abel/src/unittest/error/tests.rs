@@ -74,4 +74,127 @@ mod test_parser_error {
         error.set_line(90);
         assert_eq!(error.get_line(), 90);
     }
-}
\ No newline at end of file
+}
+
+mod test_parser_error_span {
+    use super::*;
+    use crate::span::{Position, Span};
+
+    #[test]
+    fn test_set_span() {
+        let mut error = ParserError::new("Testing span.", ErrorKind::SyntaxError);
+        assert_eq!(error.get_span(), None);
+        let span = Span::new(Position::new(3, 4, 20), Position::new(3, 6, 22));
+        error.set_span(span);
+        assert_eq!(error.get_span(), Some(span));
+        assert_eq!(error.get_line(), 3);
+    }
+}
+
+mod test_parser_error_render {
+    use super::*;
+    use crate::span::{Position, Span};
+
+    #[test]
+    fn test_render_without_location() {
+        let error = ParserError::new("Failed to open.", ErrorKind::FileNotFound);
+        assert_eq!(error.render(""), "error[FILE_NOT_FOUND]: Failed to open.\n");
+    }
+
+    #[test]
+    fn test_render_line_only() {
+        let mut error = ParserError::new("Bad value.", ErrorKind::SyntaxError);
+        error.set_line(2);
+        error.set_hint("Check the value.");
+        assert_eq!(error.render("{\n\"A\": x\n}"),
+                   "error[SYNTAX_ERROR]: Bad value.\n\
+                    \x20 --> line 2\n  |\n2 | \"A\": x\n  = hint: Check the value.\n");
+    }
+
+    #[test]
+    fn test_render_span() {
+        let mut error = ParserError::new("Bad value.", ErrorKind::SyntaxError);
+        error.set_span(Span::new(Position::new(12, 5, 40), Position::new(12, 8, 43)));
+        let source = format!("{}\"KEY\": abc,\n", "\n".repeat(11));
+        assert_eq!(error.render(&source),
+                   "error[SYNTAX_ERROR]: Bad value.\n   \
+                    --> line 12, column 6\n   |\n12 | \"KEY\": abc,\n   |      ^^^\n");
+    }
+
+    #[test]
+    fn test_render_tabs() {
+        let mut error = ParserError::new("Bad value.", ErrorKind::SyntaxError);
+        error.set_span(Span::new(Position::new(1, 2, 2), Position::new(2, 0, 8)));
+        assert!(error.render("\t\tabcd\nx").ends_with("1 | \t\tabcd\n  | \t\t^^^^\n"));
+    }
+}
+mod test_abel_error {
+    use super::*;
+    use std::error::Error as StdError;
+    use crate::json_loader::JsonLoader;
+
+    fn load(src: &str) -> Result<(), Box<dyn StdError>> {
+        let mut loader = JsonLoader::new();
+        loader.load_from_str(src)?;
+        Ok(())
+    }
+
+    fn load_abel(src: &str) -> Result<(), AbelError> {
+        let mut loader = JsonLoader::new();
+        loader.load_from_str(src)?;
+        Err(InternalError::new("Key 'B' is not found.", ErrorKind::KeyNotFound))?
+    }
+
+    #[test]
+    fn test_boxed_error() {
+        let error = load("[1,,]").err().unwrap();
+        let error = error.downcast_ref::<ParserError>().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::SyntaxError);
+        assert!(load("[1]").is_ok());
+    }
+
+    #[test]
+    fn test_from_conversions() {
+        let error = load_abel("[1,,]").err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::SyntaxError);
+        assert!(matches!(error, AbelError::Parser(_)));
+
+        let error = load_abel("[1]").err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::KeyNotFound);
+        assert_eq!(error.to_string(), "container operation failed");
+
+        let error = AbelError::from(io::Error::new(io::ErrorKind::NotFound, "no such file"));
+        assert_eq!(error.get_kind(), ErrorKind::FileNotFound);
+        assert_eq!(error.to_string(), "failed to read input");
+        let error = AbelError::from(io::Error::new(io::ErrorKind::BrokenPipe, "pipe closed"));
+        assert_eq!(error.get_kind(), ErrorKind::FailedToRead);
+    }
+
+    #[test]
+    fn test_source() {
+        let inner = ParserError::new("Comma is meaningless.", ErrorKind::SyntaxError);
+        let error = AbelError::from(inner.clone());
+        // Display only adds context, the wrapped error is the source
+        assert_eq!(error.to_string(), "failed to parse input");
+        let source = error.source().unwrap();
+        assert_eq!(source.downcast_ref::<ParserError>(), Some(&inner));
+        assert_eq!(source.to_string(), inner.to_string());
+        assert!(source.source().is_none());
+    }
+
+    #[test]
+    fn test_source_chain_to_io_cause() {
+        let mut loader = JsonLoader::new();
+        let error = loader.load_from_file("./src/unittest/test_files/missing.json").err().unwrap();
+        let mut chain = vec![error.to_string()];
+        let mut cause: &dyn StdError = &error;
+        while let Some(source) = cause.source() {
+            chain.push(source.to_string());
+            cause = source;
+        }
+        assert_eq!(chain.len(), 2);
+        assert_eq!(chain[0], "failed to read input");
+        let io_error = cause.downcast_ref::<io::Error>().unwrap();
+        assert_eq!(io_error.kind(), io::ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,183 @@
+//! Unittest for crate from_abel
+//! Source file: from_abel.rs
+
+use super::*;
+use crate::json_loader::JsonLoader;
+
+#[derive(Debug, PartialEq)]
+struct Server {
+    host: String,
+    port: u16,
+    tags: Vec<String>,
+}
+
+impl FromAbel for Server {
+    fn from_abel(obj: &dyn Object) -> Result<Self, FieldError> {
+        let dict = expect_dict(obj)?;
+        Ok(Server {
+            host: get_field(dict, "host")?,
+            port: get_field(dict, "port")?,
+            tags: get_field::<Option<Vec<String>>>(dict, "tags")?.unwrap_or_default(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Config {
+    name: String,
+    debug: bool,
+    ratio: f64,
+    timeout: Option<i32>,
+    servers: Vec<Server>,
+    limits: HashMap<String, i64>,
+    origin: (f64, f64),
+}
+
+impl FromAbel for Config {
+    fn from_abel(obj: &dyn Object) -> Result<Self, FieldError> {
+        let dict = expect_dict(obj)?;
+        Ok(Config {
+            name: get_field(dict, "name")?,
+            debug: get_field(dict, "debug")?,
+            ratio: get_field(dict, "ratio")?,
+            timeout: get_field(dict, "timeout")?,
+            servers: get_field(dict, "servers")?,
+            limits: get_field(dict, "limits")?,
+            origin: get_field(dict, "origin")?,
+        })
+    }
+}
+
+const CONFIG: &str = r#"{
+    "name": "main",
+    "debug": false,
+    "ratio": 0.5,
+    "servers": [
+        {"host": "a.example", "port": 80, "tags": ["web"]},
+        {"host": "b.example", "port": 8080}
+    ],
+    "limits": {"memory": 1024, "cpu": 4},
+    "origin": [1.5, -2]
+}"#;
+
+mod test_from_dict {
+    use super::*;
+
+    #[test]
+    fn test_struct() {
+        let config: Config = from_dict(&JsonLoader::new().load_dict_from_str(CONFIG).unwrap()).unwrap();
+        assert_eq!(config.name, "main");
+        assert!(!config.debug);
+        assert_eq!(config.ratio, 0.5);
+        assert_eq!(config.timeout, None);
+        assert_eq!(config.servers, vec![
+            Server { host: "a.example".to_string(), port: 80, tags: vec!["web".to_string()] },
+            Server { host: "b.example".to_string(), port: 8080, tags: vec![] },
+        ]);
+        assert_eq!(config.limits.len(), 2);
+        assert_eq!(config.limits["memory"], 1024);
+        assert_eq!(config.origin, (1.5, -2.0));
+    }
+
+    #[test]
+    fn test_null_is_none() {
+        let dict = JsonLoader::new().load_dict_from_str(r#"{"timeout": null, "retries": 3}"#).unwrap();
+        let timeout: Option<i32> = get_field(&dict, "timeout").unwrap();
+        assert_eq!(timeout, None);
+        let retries: Option<i32> = get_field(&dict, "retries").unwrap();
+        assert_eq!(retries, Some(3));
+    }
+
+    #[test]
+    fn test_integer_from_json_plus() {
+        let mut loader = JsonLoader::new_plus();
+        let dict = loader.load_dict_from_str("{\"port\": 443}").unwrap();
+        let port: u16 = get_field(&dict, "port").unwrap();
+        assert_eq!(port, 443);
+        let port: f64 = get_field(&dict, "port").unwrap();
+        assert_eq!(port, 443.0);
+    }
+
+    #[test]
+    fn test_from_list() {
+        let mut loader = JsonLoader::new();
+        let list = loader.load_root_from_str("[[1, \"a\"], [2, \"b\"]]").unwrap().into_list().unwrap();
+        let pairs: Vec<(u8, String)> = from_list(&list).unwrap();
+        assert_eq!(pairs, vec![(1, "a".to_string()), (2, "b".to_string())]);
+    }
+}
+
+mod test_field_errors {
+    use super::*;
+
+    #[test]
+    fn test_mismatched_type_path() {
+        let src = CONFIG.replace("\"port\": 8080", "\"port\": \"8080\"");
+        let error = from_dict::<Config>(&JsonLoader::new().load_dict_from_str(&src).unwrap()).err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::MismatchedType);
+        assert_eq!(error.get_path(), "servers[1].port");
+        assert_eq!(error.get_msg(), "expected Integer, found Text");
+        assert_eq!(error.to_string(),
+                   "MISMATCHED_TYPE(servers[1].port: expected Integer, found Text)");
+    }
+
+    #[test]
+    fn test_missing_key() {
+        let src = CONFIG.replace("\"host\": \"b.example\", ", "");
+        let error = from_dict::<Config>(&JsonLoader::new().load_dict_from_str(&src).unwrap()).err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::KeyNotFound);
+        assert_eq!(error.get_path(), "servers[1].host");
+    }
+
+    #[test]
+    fn test_nested_paths() {
+        let dict = JsonLoader::new().load_dict_from_str(r#"{"grid": [[1, 2], [3, 4.5]], "tags": {"a": ["x", 1]}}"#).unwrap();
+        let error = get_field::<Vec<Vec<i32>>>(&dict, "grid").err().unwrap();
+        assert_eq!(error.get_path(), "grid[1][1]");
+        let error = get_field::<HashMap<String, Vec<String>>>(&dict, "tags").err().unwrap();
+        assert_eq!(error.get_path(), "tags.a[1]");
+        assert_eq!(error.get_msg(), "expected Text, found Double");
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let dict = JsonLoader::new().load_dict_from_str(r#"{"port": 70000, "count": -1}"#).unwrap();
+        let error = get_field::<u16>(&dict, "port").err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::IncompatibleType);
+        assert_eq!(error.to_string(), "INCOMPATIBLE_TYPE(port: 70000.0 is out of range of u16)");
+        assert!(get_field::<usize>(&dict, "count").is_err());
+        assert_eq!(get_field::<i64>(&dict, "count").unwrap(), -1);
+    }
+
+    #[test]
+    fn test_out_of_range_at_type_limits() {
+        let dict = JsonLoader::new().load_dict_from_str(r#"{"i63": 9223372036854775808, "i64_min": -9223372036854775808,
+                                "u64": 18446744073709551616, "u32": 4294967295}"#).unwrap();
+        assert!(get_field::<i64>(&dict, "i63").is_err());
+        assert_eq!(get_field::<u64>(&dict, "i63").unwrap(), 1 << 63);
+        assert_eq!(get_field::<i64>(&dict, "i64_min").unwrap(), i64::MIN);
+        assert!(get_field::<u64>(&dict, "u64").is_err());
+        assert_eq!(get_field::<u32>(&dict, "u32").unwrap(), u32::MAX);
+    }
+
+    #[test]
+    fn test_tuple_length() {
+        let dict = JsonLoader::new().load_dict_from_str(r#"{"origin": [1, 2, 3]}"#).unwrap();
+        let error = get_field::<(f64, f64)>(&dict, "origin").err().unwrap();
+        assert_eq!(error.get_path(), "origin");
+        assert_eq!(error.get_msg(), "expected List of length 2, found length 3");
+    }
+
+    #[test]
+    fn test_into_abel_error() {
+        use crate::error::AbelError;
+
+        fn load(src: &str) -> Result<Config, AbelError> {
+            let dict = JsonLoader::new().load_dict_from_str(src)?;
+            Ok(from_dict(&dict)?)
+        }
+
+        assert!(matches!(load("{\"name\": 1}"), Err(AbelError::Field(_))));
+        assert!(matches!(load("{\"name\": }"), Err(AbelError::Parser(_))));
+    }
+}
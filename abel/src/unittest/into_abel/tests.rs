@@ -0,0 +1,67 @@
+//! Unittest for crate into_abel
+//! Source file: into_abel.rs
+
+use super::*;
+use crate::container::Container;
+use crate::from_abel::{FromAbel, from_dict, get_field};
+
+mod test_into_abel {
+    use super::*;
+
+    #[test]
+    fn test_scalars() {
+        assert_eq!(true.to_abel().type_name(), "Bool");
+        assert_eq!("text".to_abel().type_name(), "Text");
+        assert_eq!(1.5f64.to_abel().type_name(), "Double");
+        assert_eq!(7u16.to_abel().type_name(), "Integer");
+        // Out of the range of `Integer`
+        assert_eq!(u64::MAX.to_abel().type_name(), "Double");
+        assert_eq!(None::<i32>.to_abel().type_name(), "Null");
+        assert_eq!(Some(3).to_abel().type_name(), "Integer");
+    }
+
+    #[test]
+    fn test_containers() {
+        let mut limits = HashMap::new();
+        limits.insert("memory".to_string(), 1024);
+        limits.insert("cpu".to_string(), 4);
+        let dict = to_dict(&limits).unwrap();
+        let keys: Vec<&String> = dict.keys().collect();
+        assert_eq!(keys, vec!["cpu", "memory"]);
+        assert_eq!(*dict.get_ref::<Integer>("memory").unwrap(), 1024);
+
+        let obj = vec![(1, "a".to_string()), (2, "b".to_string())].to_abel();
+        let list = obj.as_any().downcast_ref::<List>().unwrap();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.get_ref::<List>(1).unwrap().get_ref::<Text>(1).unwrap(), "b");
+    }
+
+    #[test]
+    fn test_to_dict_mismatch() {
+        let error = to_dict(&vec![1, 2]).err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::MismatchedType);
+        assert_eq!(error.get_msg(), "expected Dict, found List");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut servers = HashMap::new();
+        servers.insert("a".to_string(), vec![Some(80u16), None]);
+        let dict = to_dict(&servers).unwrap();
+        let back: HashMap<String, Vec<Option<u16>>> = from_dict(&dict).unwrap();
+        assert_eq!(back, servers);
+        let origin: (f64, i64) = FromAbel::from_abel((1.5, -2i64).to_abel().as_ref()).unwrap();
+        assert_eq!(origin, (1.5, -2));
+    }
+
+    #[test]
+    fn test_flatten_into() {
+        let mut dict = Dict::new();
+        dict.insert_box("name", "main".to_abel());
+        let mut extra = HashMap::new();
+        extra.insert("debug", false);
+        flatten_into(&mut dict, &extra);
+        assert_eq!(dict.len(), 2);
+        assert!(!get_field::<bool>(&dict, "debug").unwrap());
+    }
+}
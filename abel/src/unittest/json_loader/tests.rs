@@ -30,7 +30,7 @@ mod test_json_loader_from_string {
         let mut json_loader = JsonLoader::new();
         assert_eq!(json_loader.parser_type, "json");
         assert_eq!(json_loader.parser_type, parser.parser_type);
-        json_loader.load_from_parser(&parser);
+        json_loader.load_from_parser(&parser).unwrap();
 
         /* Parsed root dictionary shall be the following
         {
@@ -95,7 +95,7 @@ mod test_json_plus_load_local_string {
         let mut json_plus_loader = JsonLoader::new_plus();
         assert_eq!(json_plus_loader.parser_type, "json_plus");
         assert_eq!(json_plus_loader.parser_type, plus_parser.parser_type);
-        json_plus_loader.load_from_parser(&plus_parser);
+        json_plus_loader.load_from_parser(&plus_parser).unwrap();
 
         // Downcast to a List
         let root_list = json_plus_loader.global_dict.get_ref::<List>("ROOT_KEY_")
@@ -140,7 +140,7 @@ mod test_json_loader_from_file {
     fn test_loader_from_file() {
         let mut json_loader = JsonLoader::new();
         // Current directory is one level above /src
-        json_loader.load_from_file("./src/unittest/test_files/standard_json.json");
+        json_loader.load_from_file("./src/unittest/test_files/standard_json.json").unwrap();
         // Root dictionary created
         assert_eq!(json_loader.get_global_dict().type_name(), "Dict");
 
@@ -172,7 +172,7 @@ mod test_json_plus_loader_from_file {
     fn test_loader_from_file() {
         let mut json_loader = JsonLoader::new_plus();
         // Current directory is one level above /src
-        json_loader.load_from_file("./src/unittest/test_files/json_plus.abel");
+        json_loader.load_from_file("./src/unittest/test_files/json_plus.abel").unwrap();
         // Root dictionary created
         assert_eq!(json_loader.get_global_dict().type_name(), "Dict");
 
@@ -200,4 +200,216 @@ mod test_json_plus_loader_from_file {
         assert!(target_dict.get_ref::<List>("LIST").unwrap().as_any().is::<List>());
     }
 
-}
\ No newline at end of file
+}
+
+mod test_json_loader_key_order {
+    use super::*;
+
+    #[test]
+    fn test_keys_in_file_order() {
+        let mut json_loader = JsonLoader::new();
+        json_loader.load_from_file("./src/unittest/test_files/standard_json.json").unwrap();
+        let root_list = json_loader.get_global_dict().get_ref::<List>("ROOT_KEY_").unwrap();
+        let target_dict = root_list.get_ref::<Dict>(0).unwrap();
+        let keys: Vec<&String> = target_dict.entries().into_iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, vec!["STR", "DBL", "BOOL", "LIST"]);
+    }
+}
+
+
+mod test_json_loader_errors {
+    use super::*;
+
+    #[test]
+    fn test_load_from_str() {
+        let mut json_loader = JsonLoader::new();
+        json_loader.load_from_str("[1, \"R2D2\", true]").unwrap();
+        let root_list = json_loader.get_global_dict().get_ref::<List>("ROOT_KEY_").unwrap();
+        let target_list = root_list.get_ref::<List>(0).unwrap();
+        assert_eq!(target_list.len(), 3);
+        assert_eq!(target_list.get_ref::<Text>(1).unwrap(), "R2D2");
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let mut json_loader = JsonLoader::new();
+        let error = json_loader.load_from_file("./src/unittest/test_files/missing.json")
+                               .err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::FileNotFound);
+        assert!(matches!(error, AbelError::Io(_)));
+    }
+
+    #[test]
+    fn test_syntax_error() {
+        let mut json_loader = JsonLoader::new();
+        let error = json_loader.load_from_str("{\n\"STR\": \"REGISTER\",\n\"DBL\": 5,,\n}")
+                               .err().unwrap();
+        assert!(error.get_line() > 0);
+        // Loader is still usable afterwards
+        json_loader.load_from_str("{\"DBL\": 5}").unwrap();
+    }
+
+    #[test]
+    fn test_mismatched_parser_type() {
+        let mut plus_parser = JsonParser::new_plus();
+        plus_parser.parse_string("{\"INT\": 5}").unwrap();
+        let mut json_loader = JsonLoader::new();
+        let error = json_loader.load_from_parser(&plus_parser).err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::IncompatibleType);
+    }
+}
+
+
+mod test_json_loader_root {
+    use super::*;
+
+    #[test]
+    fn test_root_dict() {
+        let mut json_loader = JsonLoader::new();
+        let root = json_loader.load_root_from_file(
+            "./src/unittest/test_files/standard_json.json").unwrap();
+        let target_dict = root.as_dict().unwrap();
+        assert_eq!(target_dict.get_ref::<Text>("STR").unwrap(), "REGISTER");
+        assert!(root.as_list().is_none());
+        // Root has been taken out of the loader
+        assert!(json_loader.get_global_dict().is_empty());
+        assert_eq!(json_loader.take_root().err().unwrap().get_kind(),
+                   ErrorKind::KeyNotFound);
+    }
+
+    #[test]
+    fn test_root_list() {
+        let mut json_loader = JsonLoader::new();
+        let root = json_loader.load_root_from_str("[1, [2, 3], {\"A\": null}]").unwrap();
+        let target_list = root.into_list().unwrap();
+        assert_eq!(target_list.len(), 3);
+        assert_eq!(*target_list.get_ref::<Double>(0).unwrap(), 1.0);
+        assert_eq!(target_list.get_ref::<List>(1).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_root_without_braces() {
+        let mut json_loader = JsonLoader::new_plus();
+        let root = json_loader.load_root_from_str("\"INT\": 5,\n\"DBL\": 0.5").unwrap();
+        let target_dict = root.into_dict().unwrap();
+        assert_eq!(*target_dict.get_ref::<Integer>("INT").unwrap(), 5);
+        assert_eq!(*target_dict.get_ref::<Double>("DBL").unwrap(), 0.5);
+    }
+
+    #[test]
+    fn test_load_dict_from_str() {
+        let mut json_loader = JsonLoader::new();
+        let target_dict = json_loader.load_dict_from_str("{\"DBL\": 5}").unwrap();
+        assert_eq!(*target_dict.get_ref::<Double>("DBL").unwrap(), 5.0);
+        let error = json_loader.load_dict_from_str("[1, 2]").err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::MismatchedType);
+    }
+
+    #[test]
+    fn test_into_global_dict() {
+        let mut json_loader = JsonLoader::new();
+        json_loader.load_from_str("{\"DBL\": 5}").unwrap();
+        let global = json_loader.into_global_dict();
+        assert_eq!(global.get_ref::<List>("ROOT_KEY_").unwrap().len(), 1);
+    }
+}
+
+
+mod test_json_loader_scalar_and_empty {
+    use super::*;
+    use crate::null::Null;
+
+    #[test]
+    fn test_scalar_roots() {
+        let mut json_loader = JsonLoader::new();
+        let root = json_loader.load_root_from_str("42").unwrap();
+        assert_eq!(*root.as_scalar().unwrap().as_any()
+                        .downcast_ref::<Double>().unwrap(), 42.0);
+        let root = json_loader.load_root_from_str("  \"text\"  ").unwrap();
+        assert_eq!(root.as_scalar().unwrap().as_any()
+                       .downcast_ref::<Text>().unwrap(), "text");
+        let root = json_loader.load_root_from_str("null").unwrap();
+        assert!(root.into_scalar().unwrap().as_any().is::<Null>());
+        let root = json_loader.load_root_from_str("\"\"").unwrap();
+        assert_eq!(root.as_scalar().unwrap().as_any()
+                       .downcast_ref::<Text>().unwrap(), "");
+        assert!(root.as_dict().is_none() && root.as_list().is_none());
+
+        let mut json_plus_loader = JsonLoader::new_plus();
+        let root = json_plus_loader.load_root_from_str("0.5+1j").unwrap();
+        assert!(root.as_scalar().unwrap().as_any().is::<Complex>());
+    }
+
+    #[test]
+    fn test_scalar_root_in_global_dict() {
+        let mut json_loader = JsonLoader::new();
+        json_loader.load_from_str("true").unwrap();
+        let root_list = json_loader.get_global_dict().get_ref::<List>("ROOT_KEY_").unwrap();
+        assert_eq!(*root_list.get_ref::<Bool>(0).unwrap(), true);
+    }
+
+    #[test]
+    fn test_empty_containers() {
+        let mut json_loader = JsonLoader::new();
+        assert!(json_loader.load_root_from_str("{}").unwrap().into_dict().unwrap().is_empty());
+        assert!(json_loader.load_root_from_str("[ ]").unwrap().into_list().unwrap().is_empty());
+        assert!(json_loader.load_root_from_str("{\n}").unwrap().into_dict().unwrap().is_empty());
+
+        let root = json_loader.load_root_from_str("{\"A\": {}, \"B\": [], \"C\": [[], {}]}").unwrap();
+        let target_dict = root.into_dict().unwrap();
+        assert!(target_dict.get_ref::<Dict>("A").unwrap().is_empty());
+        assert!(target_dict.get_ref::<List>("B").unwrap().is_empty());
+        let nested = target_dict.get_ref::<List>("C").unwrap();
+        assert!(nested.get_ref::<List>(0).unwrap().is_empty());
+        assert!(nested.get_ref::<Dict>(1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_empty_strings() {
+        let mut json_loader = JsonLoader::new();
+        let root = json_loader.load_root_from_str("{\"A\": \"\"}").unwrap();
+        assert_eq!(root.into_dict().unwrap().get_ref::<Text>("A").unwrap(), "");
+        let root = json_loader.load_root_from_str("[\"\", \"\"]").unwrap();
+        assert_eq!(root.into_list().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_empty_document() {
+        let mut json_loader = JsonLoader::new();
+        let error = json_loader.load_from_str("  ").err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::SyntaxError);
+    }
+}
+
+mod test_json_loader_reader {
+    use super::*;
+    use std::fs::File;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_load_from_reader() {
+        let mut json_loader = JsonLoader::new();
+        json_loader.load_from_reader(Cursor::new("{\"DBL\": 5}")).unwrap();
+        let root = json_loader.take_root().unwrap().into_dict().unwrap();
+        assert_eq!(*root.get_ref::<Double>("DBL").unwrap(), 5.0);
+
+        let file = File::open("./src/unittest/test_files/standard_json.json").unwrap();
+        json_loader.load_from_reader(file).unwrap();
+        let root = json_loader.take_root().unwrap().into_dict().unwrap();
+        assert_eq!(root.get_ref::<Text>("STR").unwrap(), "REGISTER");
+    }
+}
+
+
+mod test_json_loader_spans {
+    use super::*;
+
+    #[test]
+    fn test_loader_error_span() {
+        let mut json_plus_loader = JsonLoader::new_plus();
+        // Key without a value, the closing brace is found instead
+        let error = json_plus_loader.load_from_str("{\n  \"A\": 1,\n  \"B\":\n}").err().unwrap();
+        let span = error.get_span().unwrap();
+        assert_eq!((span.start.line, span.start.column), (4, 0));
+    }
+}
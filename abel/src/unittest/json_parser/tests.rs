@@ -94,6 +94,29 @@ mod test_json_parser {
             println!("{} \n", item);
         }
     }
+
+    #[test]
+    fn test_iter_index_after_dict() {
+        // Commas of the dictionary must not shift the indices of the list
+        let mut parser = JsonParser::new();
+        parser.parse_string("{\"A\": {\"B\": 1, \"C\": 2}, \"D\": [3, 4]}").unwrap();
+        let indices: Vec<i32> = parser.get_token_vector().iter()
+            .filter(|token| token.get_type() == JsonTokenType::IterKey && token.level == 2)
+            .map(|token| token.iter_index)
+            .collect();
+        assert_eq!(indices, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_same_key_in_sibling_dicts() {
+        // Nested dictionaries under different parents may share key names
+        let mut parser = JsonParser::new();
+        assert!(parser.parse_string("{\"x\": {\"t\": {\"p\": 1}}, \"y\": {\"t\": {\"p\": 2}}}").is_ok());
+        let mut parser = JsonParser::new();
+        let err = parser.parse_string("{\"x\": {\"t\": {\"p\": 1, \"p\": 2}}}").unwrap_err();
+        assert_eq!(err.get_kind(), ErrorKind::DuplicateKey);
+    }
+
     /*
     #[test]
     fn test_parser_parse_file() {
@@ -104,4 +127,457 @@ mod test_json_parser {
         }
     }*/
 
-}
\ No newline at end of file
+}
+
+mod test_json_parser_escapes {
+    use super::*;
+
+    // Literal of the only terminal in `{"KEY": <src>}`.
+    fn parse_value(src: &str) -> Result<String, ParserError> {
+        let mut parser = JsonParser::new();
+        parser.parse_string(&format!("{{\"KEY\": {}}}", src))?;
+        let terminal = parser.get_token_vector().iter()
+            .find(|token| token.get_type() == JsonTokenType::Terminal)
+            .unwrap();
+        Ok(terminal.literal.clone())
+    }
+
+    #[test]
+    fn test_simple_escapes() {
+        assert_eq!(parse_value(r#""say \"hi\"""#).unwrap(), "say \"hi\"");
+        assert_eq!(parse_value(r#""C:\\dir\/file""#).unwrap(), "C:\\dir/file");
+        assert_eq!(parse_value(r#""a\nb\tc\r""#).unwrap(), "a\nb\tc\r");
+        assert_eq!(parse_value(r#""\b\f""#).unwrap(), "\u{08}\u{0C}");
+        assert_eq!(parse_value(r#""\"""#).unwrap(), "\"");
+        assert_eq!(parse_value(r#""\\""#).unwrap(), "\\");
+    }
+
+    #[test]
+    fn test_unicode_escapes() {
+        assert_eq!(parse_value(r#""\u0041\u00e9\u4E2D""#).unwrap(), "Aé中");
+        assert_eq!(parse_value(r#""\u0001""#).unwrap(), "\u{01}");
+        // Surrogate pair of U+1F600
+        assert_eq!(parse_value(r#""\uD83D\uDE00!""#).unwrap(), "\u{1F600}!");
+    }
+
+    #[test]
+    fn test_escaped_key() {
+        let mut parser = JsonParser::new();
+        parser.parse_string(r#"{"A\tB": 1}"#).unwrap();
+        let key = parser.get_token_vector().iter()
+            .find(|token| token.get_type() == JsonTokenType::Key)
+            .unwrap();
+        assert_eq!(key.literal, "A\tB");
+    }
+
+    #[test]
+    fn test_invalid_escapes() {
+        let error = parse_value(r#""\x""#).err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::SyntaxError);
+        assert_eq!(error.get_line(), 1);
+        assert!(parse_value(r#""\u12G4""#).is_err());
+        // Lone or unpaired surrogates
+        assert!(parse_value(r#""\uDE00""#).is_err());
+        assert!(parse_value(r#""\uD83D""#).is_err());
+        assert!(parse_value(r#""\uD83Dx""#).is_err());
+        assert!(parse_value(r#""\uD83D\n""#).is_err());
+        assert!(parse_value(r#""\uD83D\u0041""#).is_err());
+    }
+}
+
+
+mod test_json_parser_reader {
+    use super::*;
+    use std::io::Cursor;
+
+    // Reader failing after the given text.
+    struct FailingReader {
+        text: Cursor<Vec<u8>>,
+    }
+
+    impl Read for FailingReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.text.read(buf)? {
+                0 => Err(io::Error::new(io::ErrorKind::BrokenPipe, "pipe closed")),
+                size => Ok(size),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_reader() {
+        let src = "{\n\"STR\": \"REGISTER\",\n\"LIST\": [1, 2]\n}";
+        let mut parser = JsonParser::new();
+        parser.parse_reader(Cursor::new(src)).unwrap();
+        let mut expected = JsonParser::new();
+        expected.parse_string(src).unwrap();
+        assert_eq!(parser.get_token_vector().len(), expected.get_token_vector().len());
+        assert_eq!(parser.current_line, 4);
+    }
+
+    #[test]
+    fn test_parse_buf_reader() {
+        let mut parser = JsonParser::new_plus();
+        parser.parse_buf_reader(io::BufReader::new("[5, 0b1010]".as_bytes())).unwrap();
+        assert_eq!(parser.get_root_container_type(), JsonContainerType::List);
+    }
+
+    #[test]
+    fn test_parse_reader_errors() {
+        let mut parser = JsonParser::new();
+        let error = parser.parse_reader(Cursor::new("{\n\"STR\": REGISTER\n}")).err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::SyntaxError);
+
+        let mut parser = JsonParser::new();
+        let reader = FailingReader { text: Cursor::new(b"[1,\n".to_vec()) };
+        let error = parser.parse_reader(reader).err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::FailedToRead);
+    }
+}
+
+
+mod test_json_parser_feed {
+    use super::*;
+
+    fn tokens_of(parser: &JsonParser) -> Vec<String> {
+        parser.get_token_vector().iter()
+              .map(|token| format!("{} {}", token, token.span)).collect()
+    }
+
+    fn whole(src: &str, mut parser: JsonParser) -> Vec<String> {
+        parser.parse_string(src).unwrap();
+        tokens_of(&parser)
+    }
+
+    // Feeds the text in two chunks split at every byte, and in single bytes.
+    fn assert_same_tokens(src: &str, make_parser: fn() -> JsonParser) {
+        let expected = whole(src, make_parser());
+        let bytes = src.as_bytes();
+        for split in 0..=bytes.len() {
+            let mut parser = make_parser();
+            parser.feed(&bytes[..split]).unwrap();
+            parser.feed(&bytes[split..]).unwrap();
+            parser.finish().unwrap();
+            assert_eq!(tokens_of(&parser), expected, "split at {}", split);
+        }
+        let mut parser = make_parser();
+        for byte in bytes {
+            parser.feed(std::slice::from_ref(byte)).unwrap();
+        }
+        parser.finish().unwrap();
+        assert_eq!(tokens_of(&parser), expected);
+    }
+
+    #[test]
+    fn test_feed_json() {
+        let src = "{\n  \"STR\": \"REG ISTER\",\n\n  \"UNI\": \"中\\u00e9\\uD83D\\uDE00\",\
+                   \r\n  \"LIST\": [1, 2.5, null, \"\", {}]\n}\n";
+        assert_same_tokens(src, JsonParser::new);
+    }
+
+    #[test]
+    fn test_feed_json_plus() {
+        let src = "# comment {\n{ \"INT\": 5, # another, comment\n\"CPL\": 1+2j,\n\
+                   \"BIN\": 0b1010 }";
+        assert_same_tokens(src, JsonParser::new_plus);
+    }
+
+    #[test]
+    fn test_feed_errors() {
+        let mut parser = JsonParser::new();
+        parser.feed(b"{\"STR\": \"").unwrap();
+        let error = parser.feed(&[0xff, b'"', b'}']).err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::InvalidInput);
+
+        // Incomplete character at the end of input
+        let mut parser = JsonParser::new();
+        parser.feed(&"\"中".as_bytes()[..3]).unwrap();
+        assert_eq!(parser.finish().err().unwrap().get_kind(), ErrorKind::InvalidInput);
+
+        // Syntax errors carry the line, as for whole text
+        let mut parser = JsonParser::new();
+        parser.feed(b"{\n\"A\": 1,\n\"B\"").unwrap();
+        let error = parser.feed(b"::1").err().unwrap();
+        assert_eq!(error.get_line(), 3);
+        // Input after the failing character isn't lost
+        assert_eq!(parser.feed_buffer, b"1");
+
+        let mut parser = JsonParser::new();
+        parser.feed(b"\"a").unwrap();
+        parser.feed(&[0xff, b'"']).err().unwrap();
+        assert_eq!(parser.feed_buffer, [0xff, b'"']);
+
+        let mut parser = JsonParser::new();
+        parser.feed(b"[1, 2").unwrap();
+        assert_eq!(parser.finish().err().unwrap().get_kind(), ErrorKind::UnpairedBrackets);
+    }
+}
+
+
+mod test_json_parser_spans {
+    use super::*;
+    use crate::span::{Position, Span};
+
+    fn span(start: (i32, i32, usize), end: (i32, i32, usize)) -> Span {
+        Span::new(Position::new(start.0, start.1, start.2),
+                  Position::new(end.0, end.1, end.2))
+    }
+
+    #[test]
+    fn test_token_spans() {
+        let mut parser = JsonParser::new();
+        parser.parse_string("{\r\n  \"KEY\" : \"中 x\",\n  \"LIST\": [true]\n}").unwrap();
+        let spans: Vec<(String, Span)> = parser.get_token_vector().iter()
+            .map(|token| (token.literal.clone(), token.span)).collect();
+        assert_eq!(spans, vec![
+            ("0".to_string(), span((1, 0, 0), (1, 1, 1))),
+            ("{".to_string(), span((1, 0, 0), (1, 1, 1))),
+            ("KEY".to_string(), span((2, 2, 5), (2, 7, 10))),
+            ("中 x".to_string(), span((2, 10, 13), (2, 15, 20))),
+            ("LIST".to_string(), span((3, 2, 24), (3, 8, 30))),
+            ("[".to_string(), span((3, 10, 32), (3, 11, 33))),
+            ("0".to_string(), span((3, 11, 33), (3, 15, 37))),
+            ("true".to_string(), span((3, 11, 33), (3, 15, 37))),
+            ("]".to_string(), span((3, 15, 37), (3, 16, 38))),
+            ("}".to_string(), span((4, 0, 39), (4, 1, 40))),
+        ]);
+    }
+
+    #[test]
+    fn test_error_spans() {
+        // Error at the character in error
+        let mut parser = JsonParser::new();
+        let error = parser.parse_string("{\n  \"A\": 1,,\n}").err().unwrap();
+        assert_eq!(error.get_span(), Some(span((2, 9, 11), (2, 10, 12))));
+        assert_eq!(error.get_line(), 2);
+
+        // Unrecognised literal is pointed at as a whole
+        let mut parser = JsonParser::new();
+        let error = parser.parse_string("[1, REGISTER ]").err().unwrap();
+        assert_eq!(error.get_span(), Some(span((1, 4, 4), (1, 12, 12))));
+
+        // Escape errors point at the escaped character
+        let mut parser = JsonParser::new();
+        let error = parser.parse_string("[\"\\x\"]").err().unwrap();
+        assert_eq!(error.get_span(), Some(span((1, 3, 3), (1, 4, 4))));
+
+        // Errors at the end of input
+        let mut parser = JsonParser::new();
+        let error = parser.parse_string("[1,\n 2").err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::UnpairedBrackets);
+        assert_eq!(error.get_span(), Some(span((2, 2, 6), (2, 2, 6))));
+    }
+
+    #[test]
+    fn test_spans_from_reader_and_feed() {
+        let src = "{\n\"A\": [1, 2],\r\n\"B\": \"x\"}";
+        let mut expected = JsonParser::new();
+        expected.parse_string(src).unwrap();
+        let expected: Vec<Span> = expected.get_token_vector().iter()
+            .map(|token| token.span).collect();
+
+        let mut parser = JsonParser::new();
+        parser.parse_reader(src.as_bytes()).unwrap();
+        let spans: Vec<Span> = parser.get_token_vector().iter()
+            .map(|token| token.span).collect();
+        assert_eq!(spans, expected);
+
+        let mut parser = JsonParser::new();
+        for byte in src.as_bytes() {
+            parser.feed(std::slice::from_ref(byte)).unwrap();
+        }
+        parser.finish().unwrap();
+        let spans: Vec<Span> = parser.get_token_vector().iter()
+            .map(|token| token.span).collect();
+        assert_eq!(spans, expected);
+    }
+}
+
+
+mod test_json_parser_diagnostics {
+    use super::*;
+
+    fn render_error(src: &str) -> String {
+        let mut parser = JsonParser::new();
+        parser.parse_string(src).err().unwrap().render(src)
+    }
+
+    #[test]
+    fn test_render_extra_comma() {
+        let report = render_error("{\n  \"A\": 1,,\n}");
+        assert_eq!(report, "error[SYNTAX_ERROR]: Comma can only appear after a terminal, \
+                            a string, or a container closing operator.\n\
+                            \x20 --> line 2, column 10\n\
+                            \x20 |\n\
+                            2 |   \"A\": 1,,\n\
+                            \x20 |          ^\n\
+                            \x20 = hint: Remove the extra comma.\n");
+    }
+
+    #[test]
+    fn test_render_literal() {
+        let report = render_error("[\n  1, REGISTER\n]");
+        assert!(report.starts_with("error[SYNTAX_ERROR]: Intended data type"));
+        assert!(report.contains("2 |   1, REGISTER\n  |      ^^^^^^^^\n"));
+        assert!(report.ends_with("= hint: Unquoted values are null, true, false or numbers. \
+                                  Enclose strings in double quotation marks.\n"));
+
+        let mut parser = JsonParser::new_plus();
+        let error = parser.parse_string("[0x1G]").err().unwrap();
+        assert!(error.get_hint().unwrap().starts_with("Unquoted values are null, true, false, \
+                                                       numbers, complex numbers"));
+    }
+
+    #[test]
+    fn test_render_missing_colon() {
+        let report = render_error("{\"a\" 1}");
+        assert!(report.starts_with("error[SYNTAX_ERROR]: Colon is missing after key 'a'.\n"));
+        assert!(report.ends_with("= hint: Add a colon between the key and its value.\n"));
+
+        let error = JsonParser::new().parse_string("{\"a\": \"b\"c}").err().unwrap();
+        assert_eq!(error.get_hint(), Some("Enclose the whole string in double quotation marks."));
+        let error = JsonParser::new().parse_string("{a: 1}").err().unwrap();
+        assert_eq!(error.get_hint(), Some("Enclose the key in double quotation marks."));
+    }
+
+    #[test]
+    fn test_render_missing_comma() {
+        let report = render_error("{\"A\": \"x\" \"B\": 1}");
+        assert!(report.contains("= hint: Did you forget a comma?"));
+    }
+
+    #[test]
+    fn test_render_missing_comma_after_value() {
+        let report = render_error("{\"a\": 1 \"b\": 2}");
+        assert_eq!(report, "error[SYNTAX_ERROR]: Comma is missing between two values.\n\
+                            \x20 --> line 1, column 9\n\
+                            \x20 |\n\
+                            1 | {\"a\": 1 \"b\": 2}\n\
+                            \x20 |         ^\n\
+                            \x20 = hint: Add a comma after the previous value.\n");
+        for src in ["[1 2]", "[true\nfalse]", "[[1] [2]]", "{\"a\": {} \"b\": 2}"] {
+            let mut parser = JsonParser::new();
+            let error = parser.parse_string(src).err().unwrap();
+            assert_eq!(error.get_msg(), "Comma is missing between two values.", "{}", src);
+        }
+    }
+
+    #[test]
+    fn test_render_invalid_escape_hint() {
+        let report = render_error("[\"\\x\"]");
+        assert!(report.ends_with("= hint: Valid escapes are \\\" \\\\ \\/ \\b \\f \\n \\r \\t \
+                                  and \\uXXXX.\n"));
+    }
+
+    #[test]
+    fn test_render_duplicate_key() {
+        let mut parser = JsonParser::new();
+        let error = parser.parse_string("{\"A\": 1, \"A\": 2}").err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::DuplicateKey);
+        assert_eq!(error.get_hint(), Some("Keys must be unique within a dictionary."));
+    }
+
+    #[test]
+    fn test_render_end_of_input() {
+        let report = render_error("[1,\n 2");
+        assert!(report.contains("2 |  2\n  |   ^\n"));
+        assert!(report.contains("= hint: Every opening bracket"));
+    }
+}
+
+mod test_json_parser_recovery {
+    use super::*;
+    use crate::json_loader::JsonLoader;
+    use crate::list::List;
+    use crate::double::Double;
+    use crate::container::Container;
+
+    fn parse_recovering(src: &str) -> JsonParser {
+        let mut parser = JsonParser::new();
+        parser.set_recovery_mode(true);
+        let result = parser.parse_string(src);
+        assert_eq!(result.err(), parser.get_errors().first().cloned());
+        parser
+    }
+
+    #[test]
+    fn test_recovery_mode_off_by_default() {
+        let mut parser = JsonParser::new();
+        assert!(!parser.is_recovery_mode());
+        assert!(parser.parse_string("[1, x, 3]").is_err());
+        assert!(parser.get_errors().is_empty());
+    }
+
+    #[test]
+    fn test_all_errors_reported() {
+        let src = "{\n\
+                   \"A\": 1,,\n\
+                   \"B\": REGISTER,\n\
+                   \"C\": \"\\x\",\n\
+                   \"D\": [1, {\"E\": x}, 2],\n\
+                   \"F\": 5\n\
+                   }";
+        let parser = parse_recovering(src);
+        let lines: Vec<i32> = parser.get_errors().iter().map(|error| error.get_line()).collect();
+        assert_eq!(lines, vec![2, 3, 4, 5]);
+        assert!(parser.get_errors().iter().all(|error| error.get_span().is_some()));
+    }
+
+    #[test]
+    fn test_partial_token_vector() {
+        let src = "{\"A\": 1, \"B\": REGISTER, \"C\": [1, x], \"E\": 5}";
+        let parser = parse_recovering(src);
+        assert_eq!(parser.get_errors().len(), 2);
+        // Tokens parsed successfully can be loaded
+        let mut loader = JsonLoader::new();
+        loader.load_from_parser(&parser).unwrap();
+        let root = loader.take_root().unwrap().into_dict().unwrap();
+        assert_eq!(*root.get_ref::<Double>("A").unwrap(), 1.0);
+        assert!(root.get_type_name("B").is_err());
+        let list = root.get_ref::<List>("C").unwrap();
+        assert_eq!(list.len(), 1);
+        assert_eq!(*root.get_ref::<Double>("E").unwrap(), 5.0);
+    }
+
+    #[test]
+    fn test_unpaired_brackets() {
+        let parser = parse_recovering("[1, 2]]\n[3");
+        let kinds: Vec<ErrorKind> = parser.get_errors().iter().map(|error| error.get_kind()).collect();
+        assert_eq!(kinds, vec![ErrorKind::UnpairedBrackets, ErrorKind::UnpairedBrackets]);
+
+        // Without recovery, an extra closing bracket is an error too
+        let mut parser = JsonParser::new();
+        let error = parser.parse_string("[1]]").err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::UnpairedBrackets);
+    }
+
+    #[test]
+    fn test_broken_dict_in_list() {
+        for src in ["[{\"a\" 1}]", "[{\"a\" 1}, 3]", "[1,,2, {\"a\" 1}, 3]"] {
+            let parser = parse_recovering(src);
+            let messages: Vec<&str> = parser.get_errors().iter().map(|error| error.get_msg()).collect();
+            assert!(!messages.iter().any(|msg| msg.contains("Collection scheme")), "{:?}", messages);
+        }
+        let parser = parse_recovering("[{\"a\" 1}]");
+        assert_eq!(parser.get_errors().len(), 1);
+
+        // Elements after the broken dictionary are still parsed
+        let parser = parse_recovering("[1,,2, {\"a\" 1}, 3]");
+        assert_eq!(parser.get_errors().len(), 2);
+        let mut loader = JsonLoader::new();
+        loader.load_from_parser(&parser).unwrap();
+        let root = loader.take_root().unwrap().into_list().unwrap();
+        assert_eq!(*root.get_ref::<Double>(0).unwrap(), 1.0);
+        assert_eq!(*root.get_ref::<Double>(root.len() - 1).unwrap(), 3.0);
+    }
+
+    #[test]
+    fn test_recovery_with_feed() {
+        let mut parser = JsonParser::new();
+        parser.set_recovery_mode(true);
+        parser.feed(b"[1, x").unwrap();
+        parser.feed(b"yz, 3, \"\\q\"]").unwrap();
+        assert!(parser.finish().is_err());
+        assert_eq!(parser.get_errors().len(), 2);
+    }
+}
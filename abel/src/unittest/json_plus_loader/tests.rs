@@ -18,7 +18,7 @@ mod test_json_plus_loader {
     fn test_loader_from_file() {
         let mut json_loader = JsonLoader::new_plus();
         // Current directory is one level above /src
-        json_loader.load_from_file("./src/unittest/test_files/json_plus.abel");
+        json_loader.load_from_file("./src/unittest/test_files/json_plus.abel").unwrap();
         // Root dictionary created
         assert_eq!(json_loader.get_global_dict().type_name(), "Dict");
 
@@ -0,0 +1,86 @@
+//! Unittest json_plus_writer
+//! Crate location: /src/json_plus_writer.rs
+use super::*;
+use crate::null::Null;
+use crate::bool::Bool;
+use crate::integer::Integer;
+use crate::double::Double;
+use crate::complex::Complex;
+use crate::binary::Binary;
+use crate::bitstring::Bitstring;
+use crate::text::Text;
+use crate::container::Container;
+use crate::list::List;
+use crate::dict::Dict;
+use crate::json_parser::JsonParser;
+use crate::json_loader::JsonLoader;
+
+mod test_json_plus_writer {
+    use super::*;
+
+    #[test]
+    fn test_scalars() {
+        let writer = make_json_plus_writer();
+        assert_eq!(writer.get_writer_type(), "json_plus");
+        assert_eq!(writer.write_to_string(&Integer::new(-5)).unwrap(), "-5");
+        assert_eq!(writer.write_to_string(&Double::new(5.0)).unwrap(), "5.0");
+        assert_eq!(writer.write_to_string(&Double::new(1.0e-10)).unwrap(), "1e-10");
+        assert_eq!(writer.write_to_string(&Complex::new(0.1, 2.0)).unwrap(), "0.1+2.0j");
+        assert_eq!(writer.write_to_string(&Complex::new(0.0, -0.01)).unwrap(), "0.0-0.01j");
+        assert_eq!(writer.write_to_string(&Binary::new("0b1010")).unwrap(), "0b1010");
+        assert_eq!(writer.write_to_string(&Bitstring::new("_b10.1")).unwrap(), "_b10.1");
+    }
+
+    #[test]
+    fn test_non_finite_complex() {
+        let writer = make_json_plus_writer();
+        let error = writer.write_to_string(&Complex::new(f64::NAN, 1.0)).unwrap_err();
+        assert_eq!(error.get_header(), "INCOMPATIBLE_TYPE");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut subdict = Dict::new();
+        subdict.insert("DBL", Double::new(0.5));
+        let mut list = List::new();
+        list.push(Integer::new(100));
+        list.push(Text::new("R2D2"));
+        list.push(Null::new());
+        let mut dict = Dict::new();
+        dict.insert("STR", Text::new("REGISTER"));
+        dict.insert("INT", Integer::new(5));
+        dict.insert("DBL", Double::new(5.0));
+        dict.insert("BOOL", Bool::new(true));
+        dict.insert("CPL1", Complex::new(0.005, 1.0e-10));
+        dict.insert("CPL2", Complex::new(-1.5, -2.0));
+        dict.insert("BIN", Binary::new("0b101010"));
+        dict.insert("BIS", Bitstring::new("_b101.010"));
+        dict.insert("LIST", list);
+        dict.insert("DICT", subdict);
+        let text = make_json_plus_writer().write_to_string(&dict).unwrap();
+
+        let mut parser = JsonParser::new_plus();
+        parser.parse_string(&text).unwrap();
+        let mut loader = JsonLoader::new_plus();
+        loader.load_from_parser(&parser).unwrap();
+        let root_list = loader.get_global_dict().get_ref::<List>("ROOT_KEY_").unwrap();
+        let loaded = root_list.get_ref::<Dict>(0).unwrap();
+        assert_eq!(loaded.get_ref::<Text>("STR").unwrap(), "REGISTER");
+        assert_eq!(*loaded.get_ref::<Integer>("INT").unwrap(), 5);
+        assert_eq!(*loaded.get_ref::<Double>("DBL").unwrap(), 5.0);
+        assert_eq!(*loaded.get_ref::<Bool>("BOOL").unwrap(), true);
+        assert_eq!(*loaded.get_ref::<Complex>("CPL1").unwrap(), Complex::new(0.005, 1.0e-10));
+        assert_eq!(*loaded.get_ref::<Complex>("CPL2").unwrap(), Complex::new(-1.5, -2.0));
+        assert_eq!(*loaded.get_ref::<Binary>("BIN").unwrap(), Binary::new("0b101010"));
+        assert_eq!(*loaded.get_ref::<Bitstring>("BIS").unwrap(), Bitstring::new("_b101.010"));
+        let loaded_list = loaded.get_ref::<List>("LIST").unwrap();
+        assert_eq!(*loaded_list.get_ref::<Integer>(0).unwrap(), 100);
+        assert_eq!(loaded_list.get_ref::<Text>(1).unwrap(), "R2D2");
+        assert_eq!(loaded_list.get_type_name(2).unwrap(), "Null");
+        let loaded_dict = loaded.get_ref::<Dict>("DICT").unwrap();
+        assert_eq!(*loaded_dict.get_ref::<Double>("DBL").unwrap(), 0.5);
+
+        // Writing the loaded tree again gives identical text
+        assert_eq!(make_json_plus_writer().write_to_string(loaded).unwrap(), text);
+    }
+}
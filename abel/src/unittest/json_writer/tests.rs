@@ -0,0 +1,248 @@
+//! Unittest crate json_writer
+//! Crate location: /src/json_writer.rs
+use super::*;
+use crate::null::Null;
+use crate::integer::Integer;
+use crate::container::Container;
+use crate::json_parser::JsonParser;
+use crate::json_loader::JsonLoader;
+
+mod test_escape_string {
+    use super::*;
+
+    #[test]
+    fn test_plain_string() {
+        assert_eq!(escape_string("REGISTER"), "REGISTER");
+        assert_eq!(escape_string("a/b c"), "a/b c");
+    }
+
+    #[test]
+    fn test_escaped_characters() {
+        assert_eq!(escape_string("say \"hi\""), "say \\\"hi\\\"");
+        assert_eq!(escape_string("C:\\dir"), "C:\\\\dir");
+        assert_eq!(escape_string("a\nb\tc\r"), "a\\nb\\tc\\r");
+        assert_eq!(escape_string("\u{08}\u{0C}"), "\\b\\f");
+        assert_eq!(escape_string("\u{01}"), "\\u0001");
+    }
+}
+
+mod test_json_writer {
+    use super::*;
+
+    #[test]
+    fn test_scalars() {
+        let writer = JsonWriter::new();
+        assert_eq!(writer.get_writer_type(), "json");
+        assert_eq!(writer.write_to_string(&Null::new()).unwrap(), "null");
+        assert_eq!(writer.write_to_string(&Bool::new(true)).unwrap(), "true");
+        assert_eq!(writer.write_to_string(&Double::new(0.5)).unwrap(), "0.5");
+        assert_eq!(writer.write_to_string(&Text::new("R2D2")).unwrap(), "\"R2D2\"");
+    }
+
+    #[test]
+    fn test_containers() {
+        let mut list = List::new();
+        list.push(Double::new(100.0));
+        list.push(Text::new("R2D2"));
+        list.push(Null::new());
+        let mut dict = Dict::new();
+        dict.insert("STR", Text::new("REGISTER"));
+        dict.insert("BOOL", Bool::new(true));
+        dict.insert("LIST", list);
+        dict.insert("EMPTY", Dict::new());
+
+        let writer = JsonWriter::new();
+        assert_eq!(
+            writer.write_to_string(&dict).unwrap(),
+            "{\"STR\":\"REGISTER\",\"BOOL\":true,\"LIST\":[100,\"R2D2\",null],\"EMPTY\":{}}"
+        );
+    }
+
+    #[test]
+    fn test_boxed_object() {
+        let boxed: Box<dyn Object> = Box::new(List::from_slice(&[1.0, 2.5]));
+        let writer = JsonWriter::new();
+        assert_eq!(writer.write_to_string(&boxed).unwrap(), "[1,2.5]");
+    }
+
+    #[test]
+    fn test_incompatible_types() {
+        let mut dict = Dict::new();
+        dict.insert("INT", Integer::new(5));
+        let writer = JsonWriter::new();
+        let error = writer.write_to_string(&dict).unwrap_err();
+        assert_eq!(error.get_header(), "INCOMPATIBLE_TYPE");
+
+        let error = writer.write_to_string(&Double::new(f64::NAN)).unwrap_err();
+        assert_eq!(error.get_header(), "INCOMPATIBLE_TYPE");
+    }
+
+    #[test]
+    fn test_non_finite_values() {
+        let plus_writer = JsonWriter::new_plus();
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            for writer in [&JsonWriter::new(), &plus_writer] {
+                let error = writer.write_to_string(&Double::new(value)).unwrap_err();
+                assert_eq!(error.get_kind(), ErrorKind::IncompatibleType);
+            }
+            let error = plus_writer.write_to_string(&Complex::new(1.0, value)).unwrap_err();
+            assert_eq!(error.get_kind(), ErrorKind::IncompatibleType);
+            let error = plus_writer.write_to_string(&Complex::new(value, 1.0)).unwrap_err();
+            assert_eq!(error.get_kind(), ErrorKind::IncompatibleType);
+        }
+        assert_eq!(plus_writer.write_to_string(&Double::new(0.5)).unwrap(), "0.5");
+    }
+
+    #[test]
+    fn test_extreme_magnitudes() {
+        let writer = JsonWriter::new();
+        assert_eq!(writer.write_to_string(&Double::new(1e300)).unwrap(), "1e300");
+        assert_eq!(writer.write_to_string(&Double::new(1e-300)).unwrap(), "1e-300");
+        assert_eq!(writer.write_to_string(&Double::new(-2.5e-7)).unwrap(), "-2.5e-7");
+        assert_eq!(writer.write_to_string(&Double::new(100.0)).unwrap(), "100");
+        // Both writers' output is read back as the same Double
+        for writer in [writer, JsonWriter::new_plus()] {
+            for value in [1e300, 1e-300, f64::MAX, f64::MIN_POSITIVE, 0.1] {
+                let mut list = List::new();
+                list.push(Double::new(value));
+                let text = writer.write_to_string(&list).unwrap();
+                let mut loader = JsonLoader::new_plus();
+                let root = loader.load_root_from_str(&text).unwrap().into_list().unwrap();
+                assert_eq!(*root.get_ref::<Double>(0).unwrap(), value);
+            }
+        }
+    }
+
+    #[test]
+    fn test_write_to_writer() {
+        let mut dict = Dict::new();
+        dict.insert("DBL", Double::new(5.0));
+        let mut buffer = Vec::<u8>::new();
+        JsonWriter::new().write_to(&dict, &mut buffer).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), "{\"DBL\":5}");
+    }
+}
+
+mod test_json_writer_round_trip {
+    use super::*;
+
+    #[test]
+    fn test_write_then_load() {
+        let mut dict = Dict::new();
+        dict.insert("STR", Text::new("REGISTER"));
+        dict.insert("DBL", Double::new(5.0));
+        dict.insert("LIST", List::from_slice(&[100.0, 0.25]));
+        let text = JsonWriter::new().write_to_string(&dict).unwrap();
+
+        let mut parser = JsonParser::new();
+        parser.parse_string(&text).unwrap();
+        let mut loader = JsonLoader::new();
+        loader.load_from_parser(&parser).unwrap();
+        let root_list = loader.get_global_dict().get_ref::<List>("ROOT_KEY_").unwrap();
+        let loaded = root_list.get_ref::<Dict>(0).unwrap();
+        assert_eq!(loaded.get_ref::<Text>("STR").unwrap(), "REGISTER");
+        assert_eq!(*loaded.get_ref::<Double>("DBL").unwrap(), 5.0);
+        let loaded_list = loaded.get_ref::<List>("LIST").unwrap();
+        assert_eq!(*loaded_list.get_ref::<Double>(1).unwrap(), 0.25);
+    }
+}
+
+mod test_json_writer_format {
+    use super::*;
+
+    fn make_dict() -> Dict {
+        let mut sub = Dict::new();
+        sub.insert("DBL", Double::new(0.5));
+        let mut dict = Dict::new();
+        dict.insert("STR", Text::new("REGISTER"));
+        dict.insert("LIST", List::from_slice(&[100.0, 2.0]));
+        dict.insert("DICT", sub);
+        dict.insert("EMPTY", List::new());
+        dict
+    }
+
+    #[test]
+    fn test_pretty() {
+        let text = to_json_string(&make_dict(), &WriterFormat::pretty()).unwrap();
+        assert_eq!(text, "{\n\
+            \x20   \"STR\": \"REGISTER\",\n\
+            \x20   \"LIST\": [100, 2],\n\
+            \x20   \"DICT\": {\n\
+            \x20       \"DBL\": 0.5\n\
+            \x20   },\n\
+            \x20   \"EMPTY\": []\n\
+            }");
+    }
+
+    #[test]
+    fn test_sorted_keys() {
+        let mut format = WriterFormat::compact();
+        format.sort_keys = true;
+        let text = to_json_string(&make_dict(), &format).unwrap();
+        assert_eq!(text, "{\"DICT\":{\"DBL\":0.5},\"EMPTY\":[],\"LIST\":[100,2],\"STR\":\"REGISTER\"}");
+    }
+
+    #[test]
+    fn test_tabs_and_expanded_lists() {
+        let mut format = WriterFormat::pretty();
+        format.use_tabs = true;
+        format.inline_short_lists = false;
+        let mut dict = Dict::new();
+        dict.insert("LIST", List::from_slice(&[1.0, 2.0]));
+        let text = to_json_string(&dict, &format).unwrap();
+        assert_eq!(text, "{\n\t\"LIST\": [\n\t\t1,\n\t\t2\n\t]\n}");
+    }
+
+    #[test]
+    fn test_max_line_width() {
+        let mut format = WriterFormat::pretty();
+        format.indent_width = 2;
+        format.max_line_width = 16;
+        let mut dict = Dict::new();
+        dict.insert("A", List::from_slice(&[1.0, 2.0]));
+        dict.insert("B", List::from_slice(&[1.0, 2.0, 3.0, 4.0]));
+        let text = to_json_string(&dict, &format).unwrap();
+        assert_eq!(text, "{\n  \"A\": [1, 2],\n  \"B\": [\n    1,\n    2,\n    3,\n    4\n  ]\n}");
+    }
+
+    #[test]
+    fn test_compact_and_setter() {
+        let mut writer = JsonWriter::new();
+        assert_eq!(*writer.get_format(), WriterFormat::compact());
+        assert_eq!(*writer.get_format(), WriterFormat::default());
+        let text = writer.write_to_string(&make_dict()).unwrap();
+        assert!(!text.contains('\n') && !text.contains(' '));
+        writer.set_format(WriterFormat::pretty());
+        assert!(writer.write_to_string(&make_dict()).unwrap().contains('\n'));
+    }
+
+    #[test]
+    fn test_pretty_json_plus() {
+        let mut dict = Dict::new();
+        dict.insert("INT", Integer::new(5));
+        dict.insert("DBL", Double::new(5.0));
+        let text = to_json_plus_string(&dict, &WriterFormat::pretty()).unwrap();
+        assert_eq!(text, "{\n    \"INT\": 5,\n    \"DBL\": 5.0\n}");
+        // Pretty JSON+ text is accepted by the JSON+ parser
+        let mut parser = JsonParser::new_plus();
+        parser.parse_string(&text).unwrap();
+    }
+}
+
+
+mod test_json_writer_escape_round_trip {
+    use super::*;
+
+    #[test]
+    fn test_escaped_text_round_trip() {
+        let original = "say \"hi\"\n\tC:\\dir \u{01} 中 \u{1F600}";
+        let mut dict = Dict::new();
+        dict.insert("TEXT", Text::new(original));
+        let text = JsonWriter::new().write_to_string(&dict).unwrap();
+
+        let mut loader = JsonLoader::new();
+        let root = loader.load_root_from_str(&text).unwrap();
+        let loaded = root.into_dict().unwrap();
+        assert_eq!(loaded.get_ref::<Text>("TEXT").unwrap(), original);
+    }
+}
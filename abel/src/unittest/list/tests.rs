@@ -502,10 +502,7 @@ mod test_list_container_trait_methods {
         }
         
         // Since list has only 1 item, set/insert at index 2 is NOT allowed
-        match test_list.set(2, Text::new("Word")) {
-            Ok(_) => panic!("Insert index is out of range! Shall not be okay."),
-            _ => {}
-        }
+        if test_list.set(2, Text::new("Word")).is_ok() { panic!("Insert index is out of range! Shall not be okay.") }
     }
 
     #[test]
@@ -517,10 +514,7 @@ mod test_list_container_trait_methods {
             _ => panic!("You shouldn't see this!"),
         }
         // Failure! Since list has only 1 item, set/insert at index 2 is NOT allowed
-        match test_list.set_from(2, "Word") {
-            Ok(_) => panic!("Insert index is out of range! Shall not be okay."),
-            _ => {}
-        }
+        if test_list.set_from(2, "Word").is_ok() { panic!("Insert index is out of range! Shall not be okay.") }
         match test_list.set_from(1, 100) {
             Ok(_) => {},
             _ => panic!("You shouldn't see this!"),
@@ -637,4 +631,133 @@ mod test_list_container_trait_methods {
         let third_item = sub_list_ref.get_ref::<Text>(2).unwrap();
         assert_eq!(*third_item, "Hello, World");
     }
-}
\ No newline at end of file
+}
+
+mod test_list_iteration {
+    use super::*;
+    use crate::integer::Integer;
+    use crate::text::Text;
+    use crate::typefy::Downcast;
+
+    #[test]
+    fn test_iter_by_reference() {
+        let list = List::from_slice(&[1, 2, 3]);
+        let mut total = 0;
+        for item in &list {
+            total += **item.to_ref::<Integer>().unwrap();
+        }
+        assert_eq!(total, 6);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut list = List::from_slice(&[1, 2, 3]);
+        for item in list.iter_mut() {
+            *item = Box::new(Text::new("x"));
+        }
+        for item in &mut list {
+            assert_eq!(item.type_name(), "Text");
+        }
+        assert_eq!(list.get_ref::<Text>(2).unwrap(), "x");
+    }
+
+    #[test]
+    fn test_from_iter_and_into_iter() {
+        let list: List = (0..4).map(|i| Box::new(Integer::new(i)) as Box<dyn Object>)
+                               .collect();
+        assert_eq!(list.len(), 4);
+        assert_eq!(*list.get_ref::<Integer>(3).unwrap(), 3);
+        let names: Vec<&str> = list.into_iter().map(|item| item.type_name()).collect();
+        assert_eq!(names, vec!["Integer"; 4]);
+    }
+}
+
+mod test_list_removal {
+    use super::*;
+    use crate::integer::Integer;
+    use crate::text::Text;
+
+    #[test]
+    fn test_remove() {
+        let mut list = List::from_slice(&[0, 1, 2]);
+        let removed = list.remove(1).unwrap();
+        assert_eq!(*removed.as_any().downcast_ref::<Integer>().unwrap(), 1);
+        assert_eq!(list.len(), 2);
+        assert_eq!(*list.get_ref::<Integer>(1).unwrap(), 2);
+        assert_eq!(list.remove(2).err().unwrap().get_header(), "INDEX_OUT_OF_RANGE");
+    }
+
+    #[test]
+    fn test_pop_truncate_clear() {
+        let mut list = List::from_slice(&[0, 1, 2, 3]);
+        assert_eq!(list.pop().unwrap().type_name(), "Integer");
+        assert_eq!(list.len(), 3);
+        list.truncate(1);
+        assert_eq!(list.len(), 1);
+        list.truncate(5);
+        assert_eq!(list.len(), 1);
+        list.clear();
+        assert!(list.is_empty());
+        assert_eq!(list.pop().err().unwrap().get_header(), "INDEX_OUT_OF_RANGE");
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut list = List::new();
+        list.push(Integer::new(0));
+        list.push(Text::new("one"));
+        list.swap(0, 1).unwrap();
+        assert_eq!(list.get_ref::<Text>(0).unwrap(), "one");
+        assert_eq!(*list.get_ref::<Integer>(1).unwrap(), 0);
+        assert_eq!(list.swap(0, 2).unwrap_err().get_header(), "INDEX_OUT_OF_RANGE");
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut list = List::new();
+        list.push(Integer::new(0));
+        list.push(Text::new("one"));
+        list.push(Integer::new(2));
+        list.retain(|item| item.type_name() == "Integer");
+        assert_eq!(list.len(), 2);
+        assert_eq!(*list.get_ref::<Integer>(1).unwrap(), 2);
+    }
+}
+
+mod test_list_clone {
+    use super::*;
+    use crate::integer::Integer;
+
+    #[test]
+    fn test_deep_clone() {
+        let mut list = List::from_slice(&[1, 2]);
+        list.push(List::from_slice(&[3]));
+        let mut copy = list.try_clone().unwrap();
+        copy.get_mut_ref::<List>(2).unwrap().push(Integer::new(4));
+        copy.pop().unwrap();
+        assert_eq!(copy.len(), 2);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.get_ref::<List>(2).unwrap().len(), 1);
+        assert_eq!(*list.try_clone().unwrap().get_ref::<Integer>(1).unwrap(), 2);
+    }
+}
+
+mod test_list_format {
+    use super::*;
+    use crate::double::Double;
+    use crate::binary::Binary;
+    use crate::text::Text;
+
+    #[test]
+    fn test_display_and_debug() {
+        let mut list = List::new();
+        list.push(Double::new(0.5));
+        list.push(Binary::new("0b10"));
+        list.push(Text::new("x"));
+        list.push(List::new());
+        assert_eq!(format!("{}", list), "[0.5, 0b10, \"x\", []]");
+        assert_eq!(format!("{}", List::new()), "[]");
+        assert_eq!(format!("{:?}", list[0]), "Double { internal: 0.5 }");
+        assert_eq!(format!("{:?}", List::from_slice(&[true])), "List [Bool { internal: true }]");
+    }
+}
@@ -0,0 +1,192 @@
+//! Unittest for crate pointer
+//! Source file: pointer.rs
+
+use super::*;
+use crate::container::Container;
+use crate::double::Double;
+use crate::text::Text;
+use crate::json_loader::JsonLoader;
+
+fn load(src: &str) -> Dict {
+    let mut dict = JsonLoader::new().load_dict_from_str(src).unwrap();
+    // Empty keys are legal in pointers, though not accepted by the parser
+    dict.insert("", Double::new(2.0));
+    dict
+}
+
+const SRC: &str = r#"{
+    "servers": [
+        {"host": "a", "port": 80},
+        {"host": "b", "port": 8080},
+        {"host": "c", "port": 443}
+    ],
+    "a/b": {"m~n": 1}
+}"#;
+
+mod test_split_pointer {
+    use super::*;
+
+    #[test]
+    fn test_tokens() {
+        assert!(split_pointer("").unwrap().is_empty());
+        assert_eq!(split_pointer("/").unwrap(), vec![""]);
+        assert_eq!(split_pointer("/servers/2/port").unwrap(), vec!["servers", "2", "port"]);
+        // Leading slash is optional
+        assert_eq!(split_pointer("servers/2").unwrap(), vec!["servers", "2"]);
+        assert_eq!(split_pointer("/a~1b/m~0n").unwrap(), vec!["a/b", "m~n"]);
+        assert_eq!(split_pointer("/~01").unwrap(), vec!["~1"]);
+    }
+
+    #[test]
+    fn test_invalid_escape() {
+        let error = split_pointer("/a~2").err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::InvalidInput);
+        assert!(split_pointer("/a~").is_err());
+    }
+}
+
+mod test_get_path {
+    use super::*;
+
+    #[test]
+    fn test_dict() {
+        let dict = load(SRC);
+        assert_eq!(*dict.get_path::<Double>("servers/2/port").unwrap(), 443.0);
+        assert_eq!(*dict.get_path::<Double>("/servers/0/port").unwrap(), 80.0);
+        assert_eq!(dict.get_path::<Text>("/servers/1/host").unwrap(), "b");
+        assert_eq!(*dict.get_path::<Double>("/a~1b/m~0n").unwrap(), 1.0);
+        assert_eq!(*dict.get_path::<Double>("/").unwrap(), 2.0);
+        assert_eq!(dict.get_path::<Dict>("").unwrap().len(), 3);
+        assert_eq!(dict.get_path::<List>("/servers").unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_list() {
+        let dict = load(SRC);
+        let servers = dict.get_ref::<List>("servers").unwrap();
+        assert_eq!(servers.get_path::<Text>("/2/host").unwrap(), "c");
+        assert_eq!(servers.get_path::<Dict>("1").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_errors() {
+        let dict = load(SRC);
+        let error = dict.get_path::<Double>("/servers/1/name").err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::KeyNotFound);
+        assert_eq!(error.get_msg(), "Key \"name\" not found at \"/servers/1\".");
+
+        let error = dict.get_path::<Double>("/servers/3/port").err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::IndexOutOfRange);
+        assert_eq!(error.get_msg(),
+                   "Index 3 at \"/servers\" is out of range, the list has 3 elements.");
+
+        let error = dict.get_path::<Text>("/servers/1/port").err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::MismatchedType);
+        assert!(error.get_msg().starts_with("Value at \"/servers/1/port\" is of type Double"));
+
+        let error = dict.get_path::<Double>("/servers/1/port/x").err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::MismatchedType);
+        assert_eq!(error.get_msg(),
+                   "Value at \"/servers/1/port\" is of type Double, not a container.");
+
+        // Not an index: leading zeros, signs and the end marker
+        for segment in ["01", "-1", "x", "-"] {
+            let error = dict.get_path::<Dict>(&format!("/servers/{}", segment)).err().unwrap();
+            assert_eq!(error.get_kind(), ErrorKind::InvalidInput);
+        }
+    }
+
+    #[test]
+    fn test_get_path_mut() {
+        let mut dict = load(SRC);
+        *dict.get_path_mut::<Double>("/servers/1/port").unwrap() = Double::new(8443.0);
+        assert_eq!(*dict.get_path::<Double>("/servers/1/port").unwrap(), 8443.0);
+
+        let server = dict.get_path_mut::<Dict>("/servers/2").unwrap();
+        server.insert("tls", crate::bool::Bool::new(true));
+        assert!(dict.get_path::<crate::bool::Bool>("/servers/2/tls").is_ok());
+
+        let servers = dict.get_path_mut::<List>("/servers").unwrap();
+        servers.get_path_mut::<Dict>("/0").unwrap().clear();
+        assert!(dict.get_path::<Dict>("/servers/0").unwrap().is_empty());
+
+        let error = dict.get_path_mut::<Double>("/servers/0/port").err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::KeyNotFound);
+    }
+}
+
+mod test_set_path {
+    use super::*;
+    use crate::null::Null;
+    use crate::integer::Integer;
+
+    #[test]
+    fn test_creates_containers() {
+        let mut dict = Dict::new();
+        dict.set_path("a/b/3/c", Integer::new(5)).unwrap();
+        let list = dict.get_path::<List>("/a/b").unwrap();
+        assert_eq!(list.len(), 4);
+        for index in 0..3 {
+            assert!(list.get_path::<Null>(&index.to_string()).is_ok());
+        }
+        assert_eq!(*dict.get_path::<Integer>("/a/b/3/c").unwrap(), 5);
+
+        dict.set_path("/a/b/-", Text::new("end")).unwrap();
+        assert_eq!(dict.get_path::<Text>("/a/b/4").unwrap(), "end");
+        dict.set_path("/a/x/-/y", Text::new("first")).unwrap();
+        assert_eq!(dict.get_path::<Text>("/a/x/0/y").unwrap(), "first");
+    }
+
+    #[test]
+    fn test_replaces_values() {
+        let mut dict = load(SRC);
+        dict.set_path("/servers/1/port", Integer::new(8443)).unwrap()
+            .set_path("/servers/1/tls", crate::bool::Bool::new(true)).unwrap();
+        assert_eq!(*dict.get_path::<Integer>("/servers/1/port").unwrap(), 8443);
+        assert_eq!(dict.get_path::<Dict>("/servers/1").unwrap().len(), 3);
+        // Replacing a list element keeps the length
+        dict.set_path("/servers/0", Null::new()).unwrap();
+        assert_eq!(dict.get_path::<List>("/servers").unwrap().len(), 3);
+
+        let servers = dict.get_path_mut::<List>("/servers").unwrap();
+        servers.set_path("/2/host", Text::new("d")).unwrap();
+        assert_eq!(dict.get_path::<Text>("/servers/2/host").unwrap(), "d");
+    }
+
+    #[test]
+    fn test_errors_leave_document_untouched() {
+        let mut dict = load(SRC);
+        let error = dict.set_path("/servers/1/port/x/y", Null::new()).err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::MismatchedType);
+        assert_eq!(error.get_msg(),
+                   "Value at \"/servers/1/port\" is of type Double, not a container.");
+
+        let error = dict.set_path("/servers/first/host", Null::new()).err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::InvalidInput);
+        assert_eq!(error.get_msg(), "Segment \"first\" at \"/servers\" is not a list index.");
+
+        let error = dict.set_path("", Null::new()).err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::InvalidInput);
+        assert_eq!(dict, load(SRC));
+    }
+
+    #[test]
+    fn test_list_gap_limit() {
+        let mut dict = load(SRC);
+        let error = dict.set_path("/servers/4000000000", Null::new()).err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::IndexOutOfRange);
+        assert_eq!(error.get_msg(), "Index 4000000000 at \"/servers\" is out of range, the list \
+                                     has 3 elements and at most 1024 nulls are filled in.");
+        // Also for lists that would be created, before anything is created
+        let error = dict.set_path("/x/4000000000", Null::new()).err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::IndexOutOfRange);
+        assert!(dict.set_path("/a/b/0/1025/c", Null::new()).is_err());
+        assert_eq!(dict, load(SRC));
+
+        let path = format!("/servers/{}", 3 + MAX_LIST_GAP);
+        dict.set_path(&path, Null::new()).unwrap();
+        assert_eq!(dict.get_path::<List>("/servers").unwrap().len(), 4 + MAX_LIST_GAP);
+        dict.set_path("/x/1024", Null::new()).unwrap();
+        assert_eq!(dict.get_path::<List>("/x").unwrap().len(), 1025);
+    }
+}
@@ -0,0 +1,140 @@
+//! Unittest for crate query
+//! Source file: query.rs
+
+use super::*;
+use crate::json_loader::JsonLoader;
+
+const SRC: &str = r#"{
+    "name": "cluster",
+    "servers": [
+        {"host": "a", "port": 80, "enabled": true},
+        {"host": "b", "port": 8080, "enabled": false, "tls": {"port": 8443}},
+        {"host": "c", "port": 443, "enabled": true, "tls": {"port": 443}},
+        {"host": "d", "port": 22}
+    ],
+    "limits": {"port": 1024}
+}"#;
+
+fn paths(dict: &Dict, expr: &str) -> Vec<String> {
+    dict.query(expr).unwrap().into_iter().map(|m| m.path).collect()
+}
+
+fn hosts(dict: &Dict, expr: &str) -> Vec<String> {
+    dict.query(expr).unwrap().into_iter()
+        .map(|m| m.value.as_any().downcast_ref::<Text>().unwrap().to_string())
+        .collect()
+}
+
+mod test_parse {
+    use super::*;
+
+    #[test]
+    fn test_root_is_optional() {
+        assert_eq!(Query::parse("$.name").unwrap(), Query::parse(".name").unwrap());
+        assert_eq!(Query::parse("$['name']").unwrap(), Query::parse("$.name").unwrap());
+    }
+
+    #[test]
+    fn test_invalid_queries() {
+        for expr in ["$.", "$[", "$['a'", "$[?(@.a == )]", "$[?(@.a == 1]", "$name", "$[?(1)]"] {
+            let err = Query::parse(expr).unwrap_err();
+            assert_eq!(err.get_kind(), ErrorKind::InvalidInput, "{}", expr);
+        }
+    }
+
+    #[test]
+    fn test_error_message() {
+        let err = Query::parse("$.a[x]").unwrap_err();
+        assert_eq!(err.get_msg(), "Expected a selector at position 4 of query \"$.a[x]\".");
+    }
+}
+
+mod test_select {
+    use super::*;
+
+    #[test]
+    fn test_root() {
+        let dict = JsonLoader::new().load_dict_from_str(SRC).unwrap();
+        assert_eq!(paths(&dict, "$"), vec![""]);
+    }
+
+    #[test]
+    fn test_names_and_indices() {
+        let dict = JsonLoader::new().load_dict_from_str(SRC).unwrap();
+        assert_eq!(hosts(&dict, "$.servers[1].host"), vec!["b"]);
+        assert_eq!(hosts(&dict, "$['servers'][-1]['host']"), vec!["d"]);
+        assert_eq!(hosts(&dict, "$.servers[0, 2].host"), vec!["a", "c"]);
+        assert!(paths(&dict, "$.servers[9].host").is_empty());
+        assert!(paths(&dict, "$.missing.host").is_empty());
+    }
+
+    #[test]
+    fn test_wildcard() {
+        let dict = JsonLoader::new().load_dict_from_str(SRC).unwrap();
+        assert_eq!(hosts(&dict, "$.servers[*].host"), vec!["a", "b", "c", "d"]);
+        assert_eq!(paths(&dict, "$.limits.*"), vec!["/limits/port"]);
+    }
+
+    #[test]
+    fn test_recursive_descent() {
+        let dict = JsonLoader::new().load_dict_from_str(SRC).unwrap();
+        assert_eq!(paths(&dict, "$..port"), vec![
+            "/servers/0/port", "/servers/1/port", "/servers/1/tls/port",
+            "/servers/2/port", "/servers/2/tls/port", "/servers/3/port", "/limits/port"
+        ]);
+        assert_eq!(paths(&dict, "$.servers..tls.port"), vec![
+            "/servers/1/tls/port", "/servers/2/tls/port"
+        ]);
+        assert_eq!(paths(&dict, "$..[0].host"), vec!["/servers/0/host"]);
+    }
+
+    #[test]
+    fn test_slices() {
+        let dict = JsonLoader::new().load_dict_from_str(SRC).unwrap();
+        assert_eq!(hosts(&dict, "$.servers[1:3].host"), vec!["b", "c"]);
+        assert_eq!(hosts(&dict, "$.servers[:2].host"), vec!["a", "b"]);
+        assert_eq!(hosts(&dict, "$.servers[-2:].host"), vec!["c", "d"]);
+        assert_eq!(hosts(&dict, "$.servers[::2].host"), vec!["a", "c"]);
+        assert_eq!(hosts(&dict, "$.servers[::-1].host"), vec!["d", "c", "b", "a"]);
+        assert!(paths(&dict, "$.servers[::0]").is_empty());
+        // Steps past the end of the list stop instead of overflowing
+        assert_eq!(hosts(&dict, "$.servers[1::9223372036854775807].host"), vec!["b"]);
+        assert_eq!(hosts(&dict, "$.servers[2::-9223372036854775808].host"), vec!["c"]);
+    }
+
+    #[test]
+    fn test_filters() {
+        let dict = JsonLoader::new().load_dict_from_str(SRC).unwrap();
+        assert_eq!(hosts(&dict, "$.servers[?(@.enabled == true)].host"), vec!["a", "c"]);
+        assert_eq!(hosts(&dict, "$.servers[?@.enabled != true].host"), vec!["b", "d"]);
+        assert_eq!(hosts(&dict, "$.servers[?(@.port >= 443 && @.port < 8080)].host"), vec!["c"]);
+        assert_eq!(hosts(&dict, "$.servers[?(@.tls)].host"), vec!["b", "c"]);
+        assert_eq!(hosts(&dict, "$.servers[?(!@.tls || @.host == 'c')].host"), vec!["a", "c", "d"]);
+        assert_eq!(hosts(&dict, "$.servers[?(@.tls.port == @.port)].host"), vec!["c"]);
+        assert_eq!(hosts(&dict, "$.servers[?(@.port < $.limits.port)].host"), vec!["a", "c", "d"]);
+        assert_eq!(hosts(&dict, "$.servers[?(@['host'] > \"b\")].host"), vec!["c", "d"]);
+    }
+
+    #[test]
+    fn test_filter_type_mismatch() {
+        let dict = JsonLoader::new().load_dict_from_str(SRC).unwrap();
+        assert!(paths(&dict, "$.servers[?(@.host < 1)]").is_empty());
+        assert!(paths(&dict, "$.servers[?(@.tls == null)]").is_empty());
+    }
+
+    #[test]
+    fn test_escaped_paths() {
+        let dict = JsonLoader::new().load_dict_from_str(r#"{"a/b": {"m~n": [1, 2]}}"#).unwrap();
+        assert_eq!(paths(&dict, "$['a/b']['m~n'][1]"), vec!["/a~1b/m~0n/1"]);
+    }
+
+    #[test]
+    fn test_list_root() {
+        let mut loader = JsonLoader::new();
+        let list = loader.load_root_from_str(r#"[{"a": 1}, {"a": 2}]"#).unwrap()
+            .into_list().unwrap();
+        let matches = list.query("$[?(@.a > 1)]").unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].path, "/1");
+    }
+}
@@ -0,0 +1,175 @@
+//! Unittest for crate serde_support
+//! Source file: serde_support.rs
+
+use super::*;
+use std::collections::HashMap;
+use serde::Deserialize as DeriveDeserialize;
+use crate::container::Container;
+
+#[derive(Debug, PartialEq, DeriveDeserialize)]
+struct Server {
+    host: String,
+    port: u16,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, DeriveDeserialize)]
+enum Mode {
+    Fast,
+    Limited(u32),
+    Custom { level: u8 },
+}
+
+#[derive(Debug, PartialEq, DeriveDeserialize)]
+struct Config {
+    name: String,
+    timeout: Option<f64>,
+    impedance: Complex,
+    mask: Binary,
+    bits: Bitstring,
+    servers: Vec<Server>,
+    limits: HashMap<String, i64>,
+    modes: Vec<Mode>,
+}
+
+const CONFIG: &str = r#"
+# JSON+ with types standard JSON doesn't have
+{
+    "name": "main",
+    "timeout": null,
+    "impedance": 0.1+2.0j,
+    "mask": 0b1010,
+    "bits": _b10.01,
+    "servers": [
+        {"host": "a.example", "port": 80, "tags": ["web"]},
+        {"host": "b.example", "port": 8080}
+    ],
+    "limits": {"memory": 1024},
+    "modes": ["Fast", {"Limited": 3}, {"Custom": {"level": 2}}]
+}
+"#;
+
+mod test_deserializer {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        let config: Config = from_str(CONFIG).unwrap();
+        assert_eq!(config.name, "main");
+        assert_eq!(config.timeout, None);
+        assert_eq!(config.impedance, Complex::new(0.1, 2.0));
+        assert_eq!(config.mask, Binary::new("0b1010"));
+        assert_eq!(config.bits, Bitstring::new("_b10.01"));
+        assert_eq!(config.servers[1], Server {
+            host: "b.example".to_string(), port: 8080, tags: vec![]
+        });
+        assert_eq!(config.limits["memory"], 1024);
+        assert_eq!(config.modes, vec![Mode::Fast, Mode::Limited(3), Mode::Custom { level: 2 }]);
+    }
+
+    #[test]
+    fn test_scalar_root() {
+        let value: Complex = from_str("1.0-1.5j").unwrap();
+        assert_eq!(value, Complex::new(1.0, -1.5));
+        let values: Vec<i32> = from_str("[1, 2]").unwrap();
+        assert_eq!(values, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_integral_doubles() {
+        // Standard JSON numbers are doubles
+        let mut loader = JsonLoader::new();
+        let root = loader.load_root_from_str("{\"host\": \"a\", \"port\": 443}").unwrap();
+        let server: Server = from_root(&root).unwrap();
+        assert_eq!(server.port, 443);
+    }
+
+    #[test]
+    fn test_error_paths() {
+        let src = CONFIG.replace("\"port\": 8080", "\"port\": \"8080\"");
+        let error = from_str::<Config>(&src).err().unwrap();
+        match error {
+            AbelError::Field(error) => {
+                assert_eq!(error.get_kind(), ErrorKind::MismatchedType);
+                assert_eq!(error.get_path(), "servers[1].port");
+            },
+            _ => panic!("Expected a field error"),
+        }
+
+        let src = CONFIG.replace("\"host\": \"b.example\", ", "");
+        let error = from_str::<Config>(&src).err().unwrap();
+        assert_eq!(error.get_kind(), ErrorKind::KeyNotFound);
+        let source = std::error::Error::source(&error).unwrap();
+        assert_eq!(source.to_string(), "KEY_NOT_FOUND(servers[1].host: Key is not found.)");
+
+        let error = from_str::<Config>("{\"name\": }").err().unwrap();
+        assert!(matches!(error, AbelError::Parser(_)));
+    }
+}
+
+mod test_serialize {
+    use super::*;
+
+    #[test]
+    fn test_serialize_to_json() {
+        let mut loader = JsonLoader::new_plus();
+        let root = loader.load_root_from_str(
+            "{\"b\": [1, 2.5, null, true], \"a\": {\"z\": 1-2j, \"m\": 0b11, \"s\": _b1.1}}"
+        ).unwrap();
+        let json = serde_json::to_string(root.as_dict().unwrap()).unwrap();
+        // Insertion order is kept
+        assert_eq!(json, r#"{"b":[1,2.5,null,true],"a":{"z":"1.0-2.0j","m":"0b11","s":"_b1.1"}}"#);
+    }
+
+    #[test]
+    fn test_unsupported_type() {
+        struct Opaque;
+        impl Object for Opaque {
+            fn as_any(&self) -> &dyn std::any::Any { self }
+            fn as_mut_any(&mut self) -> &mut dyn std::any::Any { self }
+            fn type_name(&self) -> &'static str { "Opaque" }
+        }
+        let mut list = List::new();
+        list.push(Opaque);
+        let error = serde_json::to_string(&list).err().unwrap();
+        assert_eq!(error.to_string(), "Type Opaque is not serializable.");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut loader = JsonLoader::new_plus();
+        let root = loader.load_root_from_str(CONFIG).unwrap();
+        let dict = root.as_dict().unwrap();
+        // Through serde_json and back
+        let json = serde_json::to_string(dict).unwrap();
+        let copy: Dict = serde_json::from_str(&json).unwrap();
+        assert_eq!(copy.len(), dict.len());
+        assert_eq!(*copy.get_ref::<Text>("mask").unwrap(), "0b1010");
+        // Through the deserializer
+        let copy: Dict = from_object(dict).unwrap();
+        let config: Config = from_object(&copy).unwrap();
+        assert_eq!(config, from_str::<Config>(CONFIG).unwrap());
+    }
+
+    #[test]
+    fn test_intrinsic_types() {
+        let complex: Complex = serde_json::from_str("\"1.0-2.0j\"").unwrap();
+        assert_eq!(complex, Complex::new(1.0, -2.0));
+        let json = serde_json::to_string(&complex).unwrap();
+        assert_eq!(serde_json::from_str::<Complex>(&json).unwrap(), complex);
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(serde_json::to_string(&Complex::new(value, 1.0)).is_err());
+            assert!(serde_json::to_string(&Complex::new(1.0, value)).is_err());
+        }
+        assert!(serde_json::from_str::<Binary>("\"0b12\"").is_err());
+        let integer: Integer = serde_json::from_str("7").unwrap();
+        assert_eq!(integer, 7);
+        assert_eq!(serde_json::to_string(&Null::new()).unwrap(), "null");
+        assert_eq!(serde_json::to_string(&Text::new("a\"b")).unwrap(), r#""a\"b""#);
+
+        let list: List = serde_json::from_str("[5000000000, \"x\", {\"k\": []}]").unwrap();
+        assert_eq!(*list.get_ref::<Double>(0).unwrap(), 5e9);
+        assert_eq!(list.get_ref::<Dict>(2).unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,23 @@
+//! Unittest span
+//! Crate location: /src/span.rs
+use super::*;
+
+mod test_span {
+    use super::*;
+
+    #[test]
+    fn test_position_after() {
+        let start = Position::new(2, 4, 10);
+        assert_eq!(start.after('a'), Position::new(2, 5, 11));
+        assert_eq!(start.after('中'), Position::new(2, 5, 13));
+    }
+
+    #[test]
+    fn test_span() {
+        let span = Span::of_char(Position::new(1, 0, 0), '{');
+        assert_eq!(span.len(), 1);
+        assert!(!span.is_empty());
+        assert_eq!(span.to_string(), "1:0-1:1");
+        assert!(Span::default().is_empty());
+    }
+}
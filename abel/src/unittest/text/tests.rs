@@ -69,6 +69,7 @@ mod test_text_deref {
     }
 
     // returns the length of a &String
+    #[allow(clippy::ptr_arg)]
     fn func_string(text: &String) -> usize {
         text.len()
     }
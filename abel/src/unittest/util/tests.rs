@@ -23,99 +23,99 @@ mod test_util_string_utils {
     fn test_has_letter() {
         // true
         let test_str = "Hel lo wor ld";
-        assert_eq!(has_letter(test_str), true);
+        assert!(has_letter(test_str));
         
         // false
         let test_str = "198727.98";
-        assert_eq!(has_letter(test_str), false);
+        assert!(!has_letter(test_str));
 
         // false
         let test_str = "";
-        assert_eq!(has_letter(test_str), false);
+        assert!(!has_letter(test_str));
 
         // true
         let test_str = " 9ab0B";
-        assert_eq!(has_letter(test_str), true);
+        assert!(has_letter(test_str));
 
         // true
         let test_str = " BAZFDFWD";
-        assert_eq!(has_letter(test_str), true);
+        assert!(has_letter(test_str));
 
         // true
         let test_str = " a c b p   ";
-        assert_eq!(has_letter(test_str), true);
+        assert!(has_letter(test_str));
 
         // true
         let test_str = " ABCDE FG XYZakl  nnihq web6";
-        assert_eq!(has_letter(test_str), true);
+        assert!(has_letter(test_str));
     }
 
     #[test]
     fn test_begins_with_letter() {
         // false
         let test_str = " Hel lo wor ld";
-        assert_eq!(begins_with_letter(test_str), false);
+        assert!(!begins_with_letter(test_str));
         
         // false
         let test_str = "198727.98";
-        assert_eq!(begins_with_letter(test_str), false);
+        assert!(!begins_with_letter(test_str));
 
         let test_str = "a.98";
-        assert_eq!(begins_with_letter(test_str), true);
+        assert!(begins_with_letter(test_str));
 
         let test_str = "$1#.98";
-        assert_eq!(begins_with_letter(test_str), false);
+        assert!(!begins_with_letter(test_str));
     }
 
     #[test]
     fn test_begins_with_sign() {
         // false
         let test_str = " Hel lo wor ld";
-        assert_eq!(begins_with_sign(test_str), false);
+        assert!(!begins_with_sign(test_str));
         
         // false
         let test_str = "198727.98";
-        assert_eq!(begins_with_sign(test_str), false);
+        assert!(!begins_with_sign(test_str));
 
         let test_str = "+ a.98";
-        assert_eq!(begins_with_sign(test_str), true);
+        assert!(begins_with_sign(test_str));
 
         let test_str = "-  $1#.98";
-        assert_eq!(begins_with_sign(test_str), true);
+        assert!(begins_with_sign(test_str));
         
         // leading space retuns false
         let test_str = " -  $1#.98";
-        assert_eq!(begins_with_sign(test_str), false);
+        assert!(!begins_with_sign(test_str));
         let test_str = "   +1.98";
-        assert_eq!(begins_with_sign(test_str), false);
+        assert!(!begins_with_sign(test_str));
     }
 
     #[test]
     fn test_is_algebraic_operator() {
         // true
         let test_str = "+";
-        assert_eq!(is_algebraic_operator(test_str), true);
+        assert!(is_algebraic_operator(test_str));
         let test_str = "-";
-        assert_eq!(is_algebraic_operator(test_str), true);
+        assert!(is_algebraic_operator(test_str));
         let test_str = "*";
-        assert_eq!(is_algebraic_operator(test_str), true);
+        assert!(is_algebraic_operator(test_str));
         let test_str = "/";
-        assert_eq!(is_algebraic_operator(test_str), true);
+        assert!(is_algebraic_operator(test_str));
 
         // false, due to leading space
         let test_str = " +";
-        assert_eq!(is_algebraic_operator(test_str), false);
+        assert!(!is_algebraic_operator(test_str));
         let test_str = "  -";
-        assert_eq!(is_algebraic_operator(test_str), false);
+        assert!(!is_algebraic_operator(test_str));
         let test_str = " *";
-        assert_eq!(is_algebraic_operator(test_str), false);
+        assert!(!is_algebraic_operator(test_str));
         let test_str = " /";
-        assert_eq!(is_algebraic_operator(test_str), false);
+        assert!(!is_algebraic_operator(test_str));
 
         let test_str = "$";
-        assert_eq!(is_algebraic_operator(test_str), false);
+        assert!(!is_algebraic_operator(test_str));
         let test_str = "%";
-        assert_eq!(is_algebraic_operator(test_str), false);
+        assert!(!is_algebraic_operator(test_str));
     }
 }
 
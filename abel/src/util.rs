@@ -22,7 +22,7 @@ pub fn is_valid_binary_string(src: &str) -> bool {
     if src.len() <= 2 { // if only two characters, false
         false
     } else {
-        if !(src.chars().next().unwrap() == '0'
+        if !(src.starts_with('0')
              && src.chars().nth(1).unwrap() == 'b')
         { // if not begins with "0b", false
             false
@@ -38,7 +38,7 @@ pub fn is_valid_bitstring(src: &str) -> bool {
     let mut dot_counter = 0;
     if strlen < 3 { // cannot be empty
         false
-    } else if !(src.chars().next().unwrap() == '_'
+    } else if !(src.starts_with('_')
                 && src.chars().nth(1).unwrap() == 'b')
     { // must begin with "_b" prefix
         false
@@ -46,7 +46,7 @@ pub fn is_valid_bitstring(src: &str) -> bool {
         false
     } else {  // rest must be either '0', '1', or dot
         //let ret = false;
-        for (_, item) in src[2..].chars().enumerate() {
+        for item in src[2..].chars() {
             if !(item == '0' || item == '1' || item == '.') {
                 ret = false;
                 break;
@@ -63,7 +63,7 @@ pub fn is_valid_bitstring(src: &str) -> bool {
                 }
             }
         }
-        return ret;
+        ret
     }
 }
 
@@ -77,15 +77,15 @@ pub fn is_algebraic_operator(src_str: &str) -> bool {
 }
 
 pub fn is_opening_symbol(sym_char: char) -> bool {
-    symbol::CONTAINER_OPENING_SYMBOLS.iter().any(|&item| sym_char == item)
+    symbol::CONTAINER_OPENING_SYMBOLS.contains(&sym_char)
 }
 
 pub fn is_closing_symbol(sym_char: char) -> bool {
-    symbol::CONTAINER_CLOSING_SYMBOLS.iter().any(|&item| sym_char == item)
+    symbol::CONTAINER_CLOSING_SYMBOLS.contains(&sym_char)
 }
 
 pub fn is_iterable_container(type_str: &str) -> bool {
-    symbol::ITERABLE_CONTAINERS.iter().any(|&item| item == type_str)
+    symbol::ITERABLE_CONTAINERS.contains(&type_str)
 }
 
 pub fn get_closing_symbol_by_opening(opening_sym: char)
@@ -0,0 +1,487 @@
+//! Derive macros `FromAbel` and `IntoAbel` for user structs and enums.
+//!
+//! Structs map to dictionaries keyed by field name. Field attributes:
+//!
+//! - `#[abel(rename = "key")]` - key in the dictionary
+//! - `#[abel(default)]` - `Default::default()` if the key is absent
+//! - `#[abel(default = "path::to::fn")]` - same, with a function
+//! - `#[abel(skip)]` - neither read nor written, always the default
+//! - `#[abel(flatten)]` - fields of a nested struct share the dictionary
+//!
+//! Enums are externally tagged by default: unit variants are texts, other
+//! variants dictionaries with the variant name as their only key. With
+//! `#[abel(tag = "type")]` on the enum, variants are dictionaries carrying
+//! the variant name under the given key. Variants can be renamed too.
+//!
+//! Newtype structs are transparent, tuple structs are lists.
+//!
+//! Structs with named fields and internally tagged enums also implement
+//! `IntoAbelDict`. Flattened fields and the payloads of internally tagged
+//! newtype variants must implement it, since they can't be written otherwise:
+//!
+//! ```compile_fail
+//! # use abel_derive::IntoAbel;
+//! #[derive(IntoAbel)]
+//! struct Config {
+//!     #[abel(flatten)]
+//!     ports: Vec<i32>,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! # use abel_derive::IntoAbel;
+//! #[derive(IntoAbel)]
+//! #[abel(tag = "kind")]
+//! enum Port {
+//!     Fixed(i32),
+//! }
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Error, Fields,
+    Generics, Ident, LitStr, Path, Result,
+};
+
+#[proc_macro_derive(FromAbel, attributes(abel))]
+pub fn derive_from_abel(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_from_abel(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(IntoAbel, attributes(abel))]
+pub fn derive_into_abel(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_into_abel(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+// Attributes
+
+enum FieldDefault {
+    None,
+    Trait,
+    Function(Path),
+}
+
+struct FieldAttrs {
+    rename: Option<String>,
+    default: FieldDefault,
+    skip: bool,
+    flatten: bool,
+}
+
+fn parse_field_attrs(attrs: &[Attribute]) -> Result<FieldAttrs> {
+    let mut field_attrs = FieldAttrs {
+        rename: None,
+        default: FieldDefault::None,
+        skip: false,
+        flatten: false,
+    };
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("abel")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                field_attrs.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("default") {
+                field_attrs.default = if meta.input.peek(syn::Token![=]) {
+                    FieldDefault::Function(meta.value()?.parse::<LitStr>()?.parse()?)
+                } else {
+                    FieldDefault::Trait
+                };
+            } else if meta.path.is_ident("skip") {
+                field_attrs.skip = true;
+            } else if meta.path.is_ident("flatten") {
+                field_attrs.flatten = true;
+            } else {
+                return Err(meta.error("unsupported field attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(field_attrs)
+}
+
+// Container attributes: `tag` on enums, `rename` on variants. Structs take
+// none.
+fn parse_name_attr(attrs: &[Attribute], name: &str) -> Result<Option<String>> {
+    let mut value = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("abel")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(name) {
+                value = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else {
+                Err(meta.error("unsupported attribute"))
+            }
+        })?;
+    }
+    Ok(value)
+}
+
+fn reject_attrs(attrs: &[Attribute]) -> Result<()> {
+    match attrs.iter().find(|attr| attr.path().is_ident("abel")) {
+        Some(attr) => Err(Error::new_spanned(attr, "unsupported attribute")),
+        None => Ok(()),
+    }
+}
+
+// Field attributes only apply to named fields.
+fn reject_unnamed_attrs(fields: &Fields) -> Result<()> {
+    match fields {
+        Fields::Unnamed(fields) => fields.unnamed.iter()
+            .try_for_each(|field| reject_attrs(&field.attrs)),
+        _ => Ok(()),
+    }
+}
+
+fn add_bounds(generics: &Generics, bound: TokenStream2) -> Generics {
+    let mut generics = generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#bound));
+    }
+    generics
+}
+
+fn field_key(ident: &Ident, attrs: &FieldAttrs) -> String {
+    attrs.rename.clone().unwrap_or_else(|| ident.to_string())
+}
+
+fn default_value(default: &FieldDefault) -> TokenStream2 {
+    match default {
+        FieldDefault::Function(path) => quote!(#path()),
+        _ => quote!(::std::default::Default::default()),
+    }
+}
+
+// FromAbel
+
+fn expand_from_abel(input: &DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+    let generics = add_bounds(&input.generics, quote!(::abel::from_abel::FromAbel));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let body = match &input.data {
+        Data::Struct(data) => {
+            reject_attrs(&input.attrs)?;
+            reject_unnamed_attrs(&data.fields)?;
+            let construct = from_fields(quote!(#name), &data.fields, quote!(obj))?;
+            quote!(::std::result::Result::Ok(#construct))
+        },
+        Data::Enum(data) => {
+            let tag = parse_name_attr(&input.attrs, "tag")?;
+            let type_name = name.to_string();
+            let mut unit_arms = Vec::new();
+            let mut payload_arms = Vec::new();
+            for variant in &data.variants {
+                reject_unnamed_attrs(&variant.fields)?;
+                let ident = &variant.ident;
+                let key = parse_name_attr(&variant.attrs, "rename")?
+                    .unwrap_or_else(|| ident.to_string());
+                match (&variant.fields, &tag) {
+                    (Fields::Unit, None) => {
+                        unit_arms.push(quote!(#key => ::std::result::Result::Ok(#name::#ident),));
+                    },
+                    (Fields::Unit, Some(_)) => {
+                        payload_arms.push(quote!(#key => ::std::result::Result::Ok(#name::#ident),));
+                    },
+                    (Fields::Unnamed(fields), Some(_)) if fields.unnamed.len() != 1 => {
+                        return Err(Error::new_spanned(
+                            variant, "tuple variants can't be internally tagged"));
+                    },
+                    (fields, Some(_)) => {
+                        let construct = from_fields(quote!(#name::#ident), fields, quote!(obj))?;
+                        payload_arms.push(quote!(#key => ::std::result::Result::Ok(#construct),));
+                    },
+                    (fields, None) => {
+                        let construct = from_fields(
+                            quote!(#name::#ident), fields, quote!(payload.as_ref()))?;
+                        payload_arms.push(quote!(#key => {
+                            (|| -> ::std::result::Result<Self, ::abel::error::FieldError> {
+                                ::std::result::Result::Ok(#construct)
+                            })().map_err(|error| error.with_key(#key))
+                        },));
+                    },
+                }
+            }
+            let unknown = quote! {
+                other => ::std::result::Result::Err(<::abel::error::FieldError as ::abel::error::Error>::new(
+                    &::std::format!("unknown variant '{}' of {}", other, #type_name),
+                    ::abel::error::ErrorKind::MismatchedType)),
+            };
+            match tag {
+                Some(tag) => quote! {
+                    let dict = ::abel::from_abel::expect_dict(obj)?;
+                    let variant: ::std::string::String = ::abel::from_abel::get_field(dict, #tag)?;
+                    match variant.as_str() {
+                        #(#payload_arms)*
+                        #unknown
+                    }
+                },
+                None => quote! {
+                    if let ::std::option::Option::Some(text) = obj.as_any().downcast_ref::<::abel::text::Text>() {
+                        return match text.as_str() {
+                            #(#unit_arms)*
+                            #unknown
+                        };
+                    }
+                    let dict = ::abel::from_abel::expect_dict(obj)?;
+                    if dict.len() != 1 {
+                        return ::std::result::Result::Err(<::abel::error::FieldError as ::abel::error::Error>::new(
+                            &::std::format!("expected a single variant of {}, found {} keys",
+                                            #type_name, dict.len()),
+                            ::abel::error::ErrorKind::MismatchedType));
+                    }
+                    let (variant, payload) = dict.iter().next().unwrap();
+                    match variant.as_str() {
+                        #(#payload_arms)*
+                        #unknown
+                    }
+                },
+            }
+        },
+        Data::Union(_) => {
+            return Err(Error::new_spanned(input, "unions are not supported"));
+        },
+    };
+    Ok(quote! {
+        impl #impl_generics ::abel::from_abel::FromAbel for #name #ty_generics #where_clause {
+            fn from_abel(obj: &dyn ::abel::object::Object)
+            -> ::std::result::Result<Self, ::abel::error::FieldError>
+            {
+                #body
+            }
+        }
+    })
+}
+
+// Expression constructing `path` from the fields found in `obj`.
+fn from_fields(path: TokenStream2, fields: &Fields, obj: TokenStream2) -> Result<TokenStream2> {
+    match fields {
+        Fields::Named(fields) => {
+            let mut inits = Vec::new();
+            let mut uses_dict = false;
+            for field in &fields.named {
+                let ident = field.ident.as_ref().unwrap();
+                let ty = &field.ty;
+                let attrs = parse_field_attrs(&field.attrs)?;
+                let key = field_key(ident, &attrs);
+                let value = if attrs.skip {
+                    default_value(&attrs.default)
+                } else if attrs.flatten {
+                    quote!(<#ty as ::abel::from_abel::FromAbel>::from_abel(#obj)?)
+                } else if let FieldDefault::None = attrs.default {
+                    uses_dict = true;
+                    quote!(::abel::from_abel::get_field::<#ty>(dict, #key)?)
+                } else {
+                    uses_dict = true;
+                    let default = default_value(&attrs.default);
+                    quote!(::abel::from_abel::get_field_or_else::<#ty, _>(dict, #key, || #default)?)
+                };
+                inits.push(quote!(#ident: #value));
+            }
+            // A dictionary is expected even if all fields are skipped or flattened
+            let dict = if uses_dict { quote!(let dict =) } else { quote!() };
+            Ok(quote!({
+                #dict ::abel::from_abel::expect_dict(#obj)?;
+                #path { #(#inits),* }
+            }))
+        },
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            let ty = &fields.unnamed[0].ty;
+            Ok(quote!(#path(<#ty as ::abel::from_abel::FromAbel>::from_abel(#obj)?)))
+        },
+        Fields::Unnamed(fields) => {
+            let types: Vec<_> = fields.unnamed.iter().map(|field| &field.ty).collect();
+            let bindings: Vec<_> = (0..types.len()).map(|index| format_ident!("field_{}", index)).collect();
+            Ok(quote!({
+                let (#(#bindings,)*) = <(#(#types,)*) as ::abel::from_abel::FromAbel>::from_abel(#obj)?;
+                #path(#(#bindings),*)
+            }))
+        },
+        Fields::Unit => Ok(quote!({
+            if !#obj.as_any().is::<::abel::null::Null>() {
+                return ::std::result::Result::Err(<::abel::error::FieldError as ::abel::error::Error>::new(
+                    &::std::format!("expected Null, found {}", #obj.type_name()),
+                    ::abel::error::ErrorKind::MismatchedType));
+            }
+            #path
+        })),
+    }
+}
+
+// IntoAbel
+
+fn expand_into_abel(input: &DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+    let generics = add_bounds(&input.generics, quote!(::abel::into_abel::IntoAbel));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let to_abel_dict = quote! {
+        ::std::boxed::Box::new(::abel::into_abel::IntoAbelDict::to_abel_dict(self))
+    };
+    // Types always written as dictionaries also get `IntoAbelDict`, with the
+    // body of `to_abel_dict`.
+    let (body, dict_body) = match &input.data {
+        Data::Struct(data) => {
+            reject_attrs(&input.attrs)?;
+            reject_unnamed_attrs(&data.fields)?;
+            let members: Vec<TokenStream2> = match &data.fields {
+                Fields::Named(fields) => fields.named.iter()
+                    .map(|field| { let ident = &field.ident; quote!(#ident) })
+                    .collect(),
+                fields => (0..fields.len())
+                    .map(|index| { let index = syn::Index::from(index); quote!(#index) })
+                    .collect(),
+            };
+            let bindings = binding_idents(members.len());
+            match &data.fields {
+                Fields::Named(_) => {
+                    let dict = dict_fields(&data.fields, &bindings, None)?;
+                    (to_abel_dict, Some(quote! {
+                        #(let #bindings = &self.#members;)*
+                        #dict
+                    }))
+                },
+                fields => {
+                    let value = into_fields(fields, &bindings)?;
+                    (quote! {
+                        #(let #bindings = &self.#members;)*
+                        #value
+                    }, None)
+                },
+            }
+        },
+        Data::Enum(data) => {
+            let tag = parse_name_attr(&input.attrs, "tag")?;
+            let mut arms = Vec::new();
+            for variant in &data.variants {
+                reject_unnamed_attrs(&variant.fields)?;
+                let ident = &variant.ident;
+                let key = parse_name_attr(&variant.attrs, "rename")?
+                    .unwrap_or_else(|| ident.to_string());
+                let bindings = binding_idents(variant.fields.len());
+                let pattern = match &variant.fields {
+                    Fields::Named(fields) => {
+                        let idents = fields.named.iter().map(|field| &field.ident);
+                        quote!(#name::#ident { #(#idents: #bindings),* })
+                    },
+                    Fields::Unnamed(_) => quote!(#name::#ident(#(#bindings),*)),
+                    Fields::Unit => quote!(#name::#ident),
+                };
+                let value = match (&variant.fields, &tag) {
+                    (Fields::Unit, None) => quote! {
+                        ::std::boxed::Box::new(::abel::text::Text::new(#key))
+                    },
+                    (Fields::Unnamed(fields), Some(_)) if fields.unnamed.len() != 1 => {
+                        return Err(Error::new_spanned(
+                            variant, "tuple variants can't be internally tagged"));
+                    },
+                    (fields, Some(tag)) => dict_fields(fields, &bindings, Some((tag, &key)))?,
+                    (fields, None) => {
+                        let payload = into_fields(fields, &bindings)?;
+                        quote!({
+                            let mut dict = ::abel::dict::Dict::new();
+                            dict.insert_box(#key, #payload);
+                            ::std::boxed::Box::new(dict)
+                        })
+                    },
+                };
+                arms.push(quote!(#pattern => #value,));
+            }
+            let body = quote! {
+                match self {
+                    #(#arms)*
+                }
+            };
+            match tag {
+                Some(_) => (to_abel_dict, Some(body)),
+                None => (body, None),
+            }
+        },
+        Data::Union(_) => {
+            return Err(Error::new_spanned(input, "unions are not supported"));
+        },
+    };
+    let dict_impl = dict_body.map(|dict_body| quote! {
+        impl #impl_generics ::abel::into_abel::IntoAbelDict for #name #ty_generics #where_clause {
+            fn to_abel_dict(&self) -> ::abel::dict::Dict {
+                #dict_body
+            }
+        }
+    });
+    Ok(quote! {
+        impl #impl_generics ::abel::into_abel::IntoAbel for #name #ty_generics #where_clause {
+            fn to_abel(&self) -> ::std::boxed::Box<dyn ::abel::object::Object> {
+                #body
+            }
+        }
+        #dict_impl
+    })
+}
+
+fn binding_idents(count: usize) -> Vec<Ident> {
+    (0..count).map(|index| Ident::new(&format!("field_{}", index), Span::call_site())).collect()
+}
+
+// Expression converting fields, bound by reference to `bindings`.
+fn into_fields(fields: &Fields, bindings: &[Ident]) -> Result<TokenStream2> {
+    match fields {
+        Fields::Named(_) => {
+            let dict = dict_fields(fields, bindings, None)?;
+            Ok(quote!(::std::boxed::Box::new(#dict)))
+        },
+        Fields::Unnamed(_) if bindings.len() == 1 => {
+            let binding = &bindings[0];
+            Ok(quote!(::abel::into_abel::IntoAbel::to_abel(#binding)))
+        },
+        Fields::Unnamed(_) => Ok(quote!({
+            let mut list = ::abel::list::List::new();
+            #(list.push_box(::abel::into_abel::IntoAbel::to_abel(#bindings));)*
+            ::std::boxed::Box::new(list)
+        })),
+        Fields::Unit => Ok(quote!(::std::boxed::Box::new(::abel::null::Null::new()))),
+    }
+}
+
+// Dictionary of named fields, or of an internally tagged variant. With a
+// tag, the variant name is added to the dictionary and a newtype payload is
+// flattened, so it must implement `IntoAbelDict` too.
+fn dict_fields(fields: &Fields, bindings: &[Ident], tag: Option<(&String, &String)>)
+-> Result<TokenStream2>
+{
+    let insert_tag = tag.map(|(tag, key)| quote! {
+        dict.insert_box(#tag, ::std::boxed::Box::new(::abel::text::Text::new(#key)));
+    });
+    let mut inserts = Vec::new();
+    match fields {
+        Fields::Named(fields) => {
+            for (field, binding) in fields.named.iter().zip(bindings) {
+                let attrs = parse_field_attrs(&field.attrs)?;
+                let key = field_key(field.ident.as_ref().unwrap(), &attrs);
+                if attrs.skip {
+                    inserts.push(quote!(let _ = #binding;));
+                } else if attrs.flatten {
+                    inserts.push(quote!(::abel::into_abel::flatten_into(&mut dict, #binding);));
+                } else {
+                    inserts.push(quote! {
+                        dict.insert_box(#key, ::abel::into_abel::IntoAbel::to_abel(#binding));
+                    });
+                }
+            }
+        },
+        Fields::Unnamed(_) => {
+            let binding = &bindings[0];
+            inserts.push(quote!(::abel::into_abel::flatten_into(&mut dict, #binding);));
+        },
+        Fields::Unit => {},
+    }
+    Ok(quote!({
+        let mut dict = ::abel::dict::Dict::new();
+        #insert_tag
+        #(#inserts)*
+        dict
+    }))
+}
@@ -0,0 +1,208 @@
+//! Tests of the derive macros against the abel crate.
+
+use std::collections::HashMap;
+
+use abel::container::Container;
+use abel::error::ErrorKind;
+use abel::from_abel::{from_dict, FromAbel};
+use abel::into_abel::{to_dict, IntoAbel};
+use abel::json_loader::JsonLoader;
+use abel::text::Text;
+use abel_derive::{FromAbel, IntoAbel};
+
+#[derive(Debug, PartialEq, FromAbel, IntoAbel)]
+struct Server {
+    host: String,
+    #[abel(default = "default_port")]
+    port: u16,
+    #[abel(rename = "tag-list", default)]
+    tags: Vec<String>,
+}
+
+fn default_port() -> u16 {
+    80
+}
+
+#[derive(Debug, PartialEq, Default, FromAbel, IntoAbel)]
+struct Meta {
+    owner: Option<String>,
+    version: i32,
+}
+
+#[derive(Debug, PartialEq, FromAbel, IntoAbel)]
+struct Config {
+    name: String,
+    servers: Vec<Server>,
+    limits: HashMap<String, i64>,
+    #[abel(flatten)]
+    meta: Meta,
+    #[abel(skip)]
+    cache: Vec<u8>,
+    mode: Mode,
+    shape: Shape,
+}
+
+#[derive(Debug, PartialEq, FromAbel, IntoAbel)]
+enum Mode {
+    Fast,
+    #[abel(rename = "careful")]
+    Careful,
+    Limited(u32),
+    Range(i32, i32),
+    Custom { level: u8 },
+}
+
+#[derive(Debug, PartialEq, FromAbel, IntoAbel)]
+#[abel(tag = "kind")]
+enum Shape {
+    Point,
+    Circle { radius: f64 },
+    Square(Side),
+}
+
+#[derive(Debug, PartialEq, FromAbel, IntoAbel)]
+struct Side {
+    side: f64,
+}
+
+#[derive(Debug, PartialEq, FromAbel, IntoAbel)]
+struct Wrapper<T>(T);
+
+#[derive(Debug, PartialEq, FromAbel, IntoAbel)]
+struct Drawing {
+    title: String,
+    #[abel(flatten)]
+    shape: Shape,
+}
+
+#[derive(Debug, PartialEq, FromAbel, IntoAbel)]
+#[abel(tag = "node")]
+enum Node {
+    Leaf(Meta),
+    Drawing(Drawing),
+}
+
+const CONFIG: &str = r#"{
+    "name": "main",
+    "servers": [
+        {"host": "a.example", "port": 8080, "tag-list": ["web"]},
+        {"host": "b.example"}
+    ],
+    "limits": {"memory": 1024},
+    "owner": "ops",
+    "version": 2,
+    "mode": {"Custom": {"level": 3}},
+    "shape": {"kind": "Circle", "radius": 1.5}
+}"#;
+
+#[test]
+fn test_from_abel_struct() {
+    let config: Config = from_dict(&JsonLoader::new().load_dict_from_str(CONFIG).unwrap()).unwrap();
+    assert_eq!(config.name, "main");
+    assert_eq!(config.servers[0], Server {
+        host: "a.example".to_string(), port: 8080, tags: vec!["web".to_string()]
+    });
+    assert_eq!(config.servers[1], Server {
+        host: "b.example".to_string(), port: 80, tags: vec![]
+    });
+    assert_eq!(config.limits["memory"], 1024);
+    assert_eq!(config.meta, Meta { owner: Some("ops".to_string()), version: 2 });
+    assert!(config.cache.is_empty());
+    assert_eq!(config.mode, Mode::Custom { level: 3 });
+    assert_eq!(config.shape, Shape::Circle { radius: 1.5 });
+}
+
+#[test]
+fn test_field_path_errors() {
+    let src = CONFIG.replace("{\"host\": \"b.example\"}", "{\"host\": \"b.example\", \"port\": \"x\"}");
+    let error = from_dict::<Config>(&JsonLoader::new().load_dict_from_str(&src).unwrap()).err().unwrap();
+    assert_eq!(error.to_string(), "MISMATCHED_TYPE(servers[1].port: expected Integer, found Text)");
+
+    let src = CONFIG.replace("\"level\": 3", "\"level\": true");
+    let error = from_dict::<Config>(&JsonLoader::new().load_dict_from_str(&src).unwrap()).err().unwrap();
+    assert_eq!(error.get_path(), "mode.Custom.level");
+
+    let src = CONFIG.replace("\"name\": \"main\",", "");
+    let error = from_dict::<Config>(&JsonLoader::new().load_dict_from_str(&src).unwrap()).err().unwrap();
+    assert_eq!(error.get_kind(), ErrorKind::KeyNotFound);
+    assert_eq!(error.get_path(), "name");
+}
+
+#[test]
+fn test_enums() {
+    let cases = [
+        (Mode::Fast, "\"Fast\""),
+        (Mode::Careful, "\"careful\""),
+        (Mode::Limited(5), "{\"Limited\": 5}"),
+        (Mode::Range(-1, 1), "{\"Range\": [-1, 1]}"),
+    ];
+    for (mode, src) in cases {
+        let dict = JsonLoader::new().load_dict_from_str(&format!("{{\"mode\": {}}}", src)).unwrap();
+        assert_eq!(abel::from_abel::get_field::<Mode>(&dict, "mode").unwrap(), mode);
+    }
+    let dict = JsonLoader::new().load_dict_from_str("{\"mode\": \"Slow\"}").unwrap();
+    let error = abel::from_abel::get_field::<Mode>(&dict, "mode").err().unwrap();
+    assert_eq!(error.to_string(), "MISMATCHED_TYPE(mode: unknown variant 'Slow' of Mode)");
+
+    let dict = JsonLoader::new().load_dict_from_str("{\"kind\": \"Square\", \"side\": 2}").unwrap();
+    assert_eq!(from_dict::<Shape>(&dict).unwrap(), Shape::Square(Side { side: 2.0 }));
+    let dict = JsonLoader::new().load_dict_from_str("{\"kind\": \"Point\"}").unwrap();
+    assert_eq!(from_dict::<Shape>(&dict).unwrap(), Shape::Point);
+    let dict = JsonLoader::new().load_dict_from_str("{\"side\": 2}").unwrap();
+    assert_eq!(from_dict::<Shape>(&dict).err().unwrap().get_path(), "kind");
+}
+
+#[test]
+fn test_into_abel() {
+    let config = Config {
+        name: "main".to_string(),
+        servers: vec![Server { host: "a".to_string(), port: 1, tags: vec![] }],
+        limits: HashMap::new(),
+        meta: Meta { owner: None, version: 1 },
+        cache: vec![1, 2],
+        mode: Mode::Careful,
+        shape: Shape::Square(Side { side: 2.0 }),
+    };
+    let dict = to_dict(&config).unwrap();
+    let keys: Vec<&String> = dict.keys().collect();
+    assert_eq!(keys, vec!["name", "servers", "limits", "owner", "version", "mode", "shape"]);
+    assert_eq!(*dict.get_ref::<Text>("mode").unwrap(), "careful");
+    let shape = dict.get_ref::<abel::dict::Dict>("shape").unwrap();
+    assert_eq!(*shape.get_ref::<Text>("kind").unwrap(), "Square");
+
+    // Round trip, apart from the skipped field
+    let back: Config = from_dict(&dict).unwrap();
+    assert_eq!(back, Config { cache: vec![], ..config });
+}
+
+#[test]
+fn test_round_trip_enums_and_generics() {
+    let modes = vec![Mode::Fast, Mode::Limited(3), Mode::Range(1, 2), Mode::Custom { level: 9 }];
+    let back: Vec<Mode> = FromAbel::from_abel(modes.to_abel().as_ref()).unwrap();
+    assert_eq!(back, modes);
+
+    let shapes = vec![Shape::Point, Shape::Circle { radius: 0.5 }];
+    let back: Vec<Shape> = FromAbel::from_abel(shapes.to_abel().as_ref()).unwrap();
+    assert_eq!(back, shapes);
+
+    let wrapper = Wrapper(vec![1, 2]);
+    assert_eq!(wrapper.to_abel().type_name(), "List");
+    let back: Wrapper<Vec<i32>> = FromAbel::from_abel(wrapper.to_abel().as_ref()).unwrap();
+    assert_eq!(back, wrapper);
+}
+
+#[test]
+fn test_round_trip_flattened_and_tagged_payloads() {
+    let drawing = Drawing { title: "sketch".to_string(), shape: Shape::Square(Side { side: 1.0 }) };
+    let dict = to_dict(&drawing).unwrap();
+    let keys: Vec<&String> = dict.keys().collect();
+    assert_eq!(keys, vec!["title", "kind", "side"]);
+    assert_eq!(from_dict::<Drawing>(&dict).unwrap(), drawing);
+
+    let nodes = vec![
+        Node::Leaf(Meta { owner: Some("ops".to_string()), version: 1 }),
+        Node::Drawing(Drawing { title: "dot".to_string(), shape: Shape::Point }),
+    ];
+    let back: Vec<Node> = FromAbel::from_abel(nodes.to_abel().as_ref()).unwrap();
+    assert_eq!(back, nodes);
+}
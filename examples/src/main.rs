@@ -37,7 +37,7 @@ fn standard_json_loader() {
     // Since we are now interested in loading standard JSON file,
     // let's create a dedicated JSON loader
     let mut json_loader = JsonLoader::new();
-    json_loader.load_from_file("./src/standard_json.json");
+    json_loader.load_from_file("./src/standard_json.json").unwrap();
 
     // We can check if the root container or global dictionary is
     // created as expected
@@ -84,7 +84,13 @@ fn standard_json_loader() {
     let double_number = entry_list.get_ref::<Double>(0).unwrap();
     // ... and its value is 100
     assert!(double_number == &100.0);
-    
+
+    // Much of the above can be skipped if we ask the loader for the root
+    // of the document directly. The root is returned as an owned value.
+    let mut json_loader = JsonLoader::new();
+    let root = json_loader.load_root_from_file("./src/standard_json.json").unwrap();
+    let target_dict = root.into_dict().unwrap();
+    assert!(target_dict.get_ref::<Text>("STR").unwrap() == "REGISTER");
 }
 
 
@@ -105,7 +111,7 @@ fn json_plus() {
     // this loader is still `JsonLoader`, its field `parser_type` is
     // set to `json_plus`.
     let mut loader = JsonLoader::new_plus();
-    loader.load_from_file("./src/json_plus.txt");
+    loader.load_from_file("./src/json_plus.txt").unwrap();
 
     // Inside the global dictionary, there is a root key "ROOT_KEY_"
     // and it points to a list container that stores the dictionary